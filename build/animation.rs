@@ -0,0 +1,117 @@
+use std::fs;
+
+const DEFAULT_DURATION: f32 = 0.1;
+
+/// Sequence of assets named `{name}_frame0`, `{name}_frame1`, ...
+/// Other names ending in a number, like `block_sq2`, are plain sprites.
+///
+/// The optional `assets/{name}.anim` file configures the clip:
+/// ```text
+/// mode = loop | once | ping_pong
+/// durations = 0.1 0.2 0.1
+/// ```
+/// A single duration is used for all the frames.
+pub struct Clip {
+    pub name: String,
+    pub frames: Vec<String>,
+    pub durations: Vec<f32>,
+    pub mode: &'static str,
+}
+
+pub fn find_clips(names: &[&String]) -> Vec<Clip> {
+    let mut clips: Vec<Clip> = Vec::new();
+
+    for name in names {
+        let Some((clip_name, index)) = name.rsplit_once("_frame") else {
+            continue;
+        };
+        let Ok(index) = index.parse::<usize>() else {
+            continue;
+        };
+
+        let clip = match clips.iter_mut().find(|clip| clip.name == clip_name) {
+            Some(clip) => clip,
+            None => {
+                clips.push(Clip {
+                    name: clip_name.to_string(),
+                    frames: Vec::new(),
+                    durations: Vec::new(),
+                    mode: "Loop",
+                });
+                clips.last_mut().unwrap()
+            }
+        };
+
+        if clip.frames.len() <= index {
+            clip.frames.resize(index + 1, String::new());
+        }
+        clip.frames[index] = name.to_string();
+    }
+
+    for clip in &mut clips {
+        if let Some(missing) = clip.frames.iter().position(|frame| frame.is_empty()) {
+            panic!("The animation {} has no frame {}", clip.name, missing);
+        }
+
+        clip.durations = vec![DEFAULT_DURATION; clip.frames.len()];
+        parse_config(clip);
+
+        // `AnimationClip::frame_at` needs a positive duration for each frame
+        if let Some(frame) = clip
+            .durations
+            .iter()
+            .position(|duration| duration.is_nan() || *duration <= 0.)
+        {
+            panic!(
+                "The frame {frame} of the animation {} has a non positive duration",
+                clip.name
+            );
+        }
+    }
+
+    clips
+}
+
+fn parse_config(clip: &mut Clip) {
+    let path = format!("assets/{}.anim", clip.name);
+    let Ok(config) = fs::read_to_string(&path) else {
+        return;
+    };
+
+    for line in config
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        let Some((key, value)) = line.split_once('=') else {
+            panic!("Invalid line in {path}: {line}");
+        };
+
+        match key.trim() {
+            "mode" => {
+                clip.mode = match value.trim() {
+                    "loop" => "Loop",
+                    "once" => "Once",
+                    "ping_pong" => "PingPong",
+                    mode => panic!("Invalid animation mode in {path}: {mode}"),
+                }
+            }
+            "durations" => {
+                let durations: Vec<f32> = value
+                    .split_whitespace()
+                    .map(|duration| duration.parse().unwrap())
+                    .collect();
+
+                clip.durations = match durations.len() {
+                    1 => vec![durations[0]; clip.frames.len()],
+                    len if len == clip.frames.len() => durations,
+                    len => panic!(
+                        "{path} has {len} durations but the animation has {} frames",
+                        clip.frames.len()
+                    ),
+                };
+            }
+            key => panic!("Invalid key in {path}: {key}"),
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use super::animation;
+use super::nine_slice;
+use super::normal_map::{self, NormalMapConfig};
 use super::*;
 use image::{DynamicImage, EncodableLayout, ImageBuffer};
 use indoc::*;
@@ -9,21 +12,36 @@ use texture_packer::{
     TexturePackerConfig,
 };
 
+/// Mip levels generated when loading the atlas (including the full size level)
+const ATLAS_MIP_LEVELS: u32 = 4;
+
 const ATLAS_CONFIG: TexturePackerConfig = TexturePackerConfig {
     max_width: 1 << 12,
     max_height: 1 << 12,
     allow_rotation: false,
     texture_outlines: false,
     border_padding: 0,
-    texture_padding: 1,
-    texture_extrusion: 0,
+    // A texel of the smallest mip level must not mix two sprites
+    texture_padding: 1 << (ATLAS_MIP_LEVELS - 1),
+    // Repeat the sprite borders, so linear filtering doesn't fade the edges
+    texture_extrusion: 1 << (ATLAS_MIP_LEVELS - 1),
     trim: true,
 };
 
+/// Used to generate the normal map of the assets without a `_norm.webp`.
+/// When `None`, a flat normal map is used instead.
+const GENERATED_NORMALS: Option<NormalMapConfig> = Some(NormalMapConfig {
+    strength: 4.,
+    bevel: 6.,
+});
+
 #[derive(Clone, Copy)]
 pub struct AtlasView {
-    pub pos: Vec2,
-    pub size: Vec2,
+    pub page: u32,
+    /// Position that the image would have without trimming
+    pub source_pos: Vec2,
+    /// Size of the image before trimming
+    pub source_size: Vec2,
 }
 
 #[derive(Default)]
@@ -35,10 +53,15 @@ fn pack_images<'a>(
     diffuse_pack: &mut MultiTexturePacker<'a, DynamicImage, String>,
     normal_pack: &mut MultiTexturePacker<'a, DynamicImage, String>,
 ) {
-    for entry in fs::read_dir("assets").unwrap() {
-        let Ok(entry) = entry else {
-            continue;
-        };
+    let mut entries: Vec<_> = fs::read_dir("assets")
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .collect();
+
+    // Keep the packing independent of the platform directory order
+    entries.sort_unstable_by_key(|entry| entry.path());
+
+    for entry in entries {
         let Ok(file_type) = entry.file_type() else {
             continue;
         };
@@ -59,10 +82,18 @@ fn pack_images<'a>(
         let image = ImageImporter::import_from_file(&path).unwrap();
 
         path.set_file_name(format!("{name}_norm.webp"));
-        let norm_image = match ImageImporter::import_from_file(&path) {
-            Ok(norm_image) => norm_image,
-            // Create a normal default normal image
-            Err(err) if err.starts_with("The system cannot find") => DynamicImage::ImageRgba8(
+        let norm_image = if path.exists() {
+            // Share the diffuse alpha, so both images are trimmed and packed equally
+            let mut norm_image = ImageImporter::import_from_file(&path).unwrap().into_rgba8();
+            for (norm, diffuse) in norm_image.pixels_mut().zip(image.to_rgba8().pixels()) {
+                norm[3] = diffuse[3];
+            }
+            DynamicImage::ImageRgba8(norm_image)
+        } else if let Some(config) = &GENERATED_NORMALS {
+            normal_map::generate(&image, config)
+        } else {
+            // Create a flat normal image
+            DynamicImage::ImageRgba8(
                 ImageBuffer::from_vec(
                     image.width(),
                     image.height(),
@@ -75,8 +106,7 @@ fn pack_images<'a>(
                         .collect::<Vec<u8>>(),
                 )
                 .unwrap(),
-            ),
-            Err(err) => panic!("{:?}", err),
+            )
         };
         normal_pack.pack_own(name.clone(), norm_image).unwrap();
 
@@ -84,14 +114,28 @@ fn pack_images<'a>(
     }
 }
 
+/// All the pages share the same size, so they can be loaded as a texture array
+fn pages_size<'a>(diffuse_pack: &MultiTexturePacker<'a, DynamicImage, String>) -> (u32, u32) {
+    diffuse_pack
+        .get_pages()
+        .iter()
+        .fold((1, 1), |(w, h), page| {
+            (w.max(page.width()), h.max(page.height()))
+        })
+}
+
 fn export_textures<'a>(
     name: &str,
-    diffuse_pack: &mut MultiTexturePacker<'a, DynamicImage, String>,
+    pack: &mut MultiTexturePacker<'a, DynamicImage, String>,
+    (width, height): (u32, u32),
 ) {
-    for (i, page) in diffuse_pack.get_pages().iter().enumerate() {
+    for (i, page) in pack.get_pages().iter().enumerate() {
         let exporter = ImageExporter::export(page).unwrap();
 
-        let encoder = webp::Encoder::from_image(&exporter).unwrap();
+        let mut image = DynamicImage::new_rgba8(width, height);
+        image::imageops::replace(&mut image, &exporter, 0, 0);
+
+        let encoder = webp::Encoder::from_image(&image).unwrap();
         let encoded_webp: webp::WebPMemory = encoder.encode_simple(true, 100.).unwrap();
 
         let path = format!("atlas/{}-{}.webp", name, i);
@@ -101,25 +145,33 @@ fn export_textures<'a>(
 
 fn generate_code<'a>(
     diffuse_pack: &mut MultiTexturePacker<'a, DynamicImage, String>,
+    (page_w, page_h): (u32, u32),
     atlas: &mut Atlas,
 ) {
     let pages_count = diffuse_pack.get_pages().len();
 
     let mut texture_views = String::with_capacity(64 * pages_count);
+    let mut slice_views = String::new();
     let mut load_diffuse_textures = String::with_capacity(64 * pages_count);
     let mut load_normal_textures = String::with_capacity(64 * pages_count);
 
-    for (page_i, page) in diffuse_pack.get_pages().iter().enumerate() {
-        let page_w = page.width() as f32;
-        let page_h = page.height() as f32;
-        let page_offset = page_i as f32;
+    let page_w = page_w as f32;
+    let page_h = page_h as f32;
+
+    let mut names: Vec<&String> = diffuse_pack
+        .get_pages()
+        .iter()
+        .flat_map(|page| page.get_frames().keys())
+        .collect();
+    names.sort_unstable();
 
+    for (page_i, page) in diffuse_pack.get_pages().iter().enumerate() {
         let mut page: Vec<_> = page.get_frames().into_iter().collect();
         page.sort_unstable_by_key(|(name, _)| *name);
 
         for (name, frame) in page {
-            let x = (frame.frame.x as f32) / page_w + page_offset;
-            let y = (frame.frame.y as f32) / page_h + page_offset;
+            let x = (frame.frame.x as f32) / page_w;
+            let y = (frame.frame.y as f32) / page_h;
 
             let pixel_w = frame.frame.w as f32;
             let pixel_h = frame.frame.h as f32;
@@ -127,11 +179,25 @@ fn generate_code<'a>(
             let w = pixel_w / page_w;
             let h = pixel_h / page_h;
 
+            // frame.source holds the trim offset and the untrimmed size
+            let source_x = frame.source.x as f32;
+            let source_y = frame.source.y as f32;
+            let source_w = frame.source.w as f32;
+            let source_h = frame.source.h as f32;
+
+            // Center of the trimmed frame relative to the center of the source image
+            let trim_offset = Vec2::new(
+                (source_x + pixel_w * 0.5) / source_w - 0.5,
+                0.5 - (source_y + pixel_h * 0.5) / source_h,
+            );
+            let trim_scale = Vec2::new(pixel_w / source_w, pixel_h / source_h);
+
             atlas.images.insert(
                 name.clone(),
                 AtlasView {
-                    pos: Vec2::new(x, y),
-                    size: Vec2::new(w, h),
+                    page: page_i as u32,
+                    source_pos: Vec2::new(x - source_x / page_w, y - source_y / page_h),
+                    source_size: Vec2::new(source_w / page_w, source_h / page_h),
                 },
             );
 
@@ -141,30 +207,50 @@ fn generate_code<'a>(
                     TextureAtlasView {{
                         pos: Vec2::new({x}f32, {y}f32),
                         size: Vec2::new({w}f32, {h}f32),
+                        page: {page_i},
                         ratio: {}f32,
+                        trim_offset: {trim_offset},
+                        trim_scale: {trim_scale},
                     }}
                 }}
-            /", pixel_w / pixel_h};
+            /", source_w / source_h};
             texture_views.pop();
+
+            if let Some(insets) = nine_slice::read_insets(name) {
+                // Borders removed by the trim, in units of the untrimmed height
+                let margins_min = Vec2::new(source_x, source_h - source_y - pixel_h) / source_h;
+                let margins_max = Vec2::new(source_w - source_x - pixel_w, source_y) / source_h;
+
+                let insets_min = Vec2::new(insets.left, insets.bottom) / source_h - margins_min;
+                let insets_max = Vec2::new(insets.right, insets.top) / source_h - margins_max;
+                let insets_min = Vec2::new(insets_min.x.max(0.), insets_min.y.max(0.));
+                let insets_max = Vec2::new(insets_max.x.max(0.), insets_max.y.max(0.));
+
+                slice_views += &formatdoc! {"
+                    #[allow(dead_code)]
+                    pub fn slice_{name}() -> NineSliceView {{
+                        NineSliceView {{
+                            view: TextureAtlas::view_{name}(),
+                            insets_min: {insets_min},
+                            insets_max: {insets_max},
+                            margins_min: {margins_min},
+                            margins_max: {margins_max},
+                        }}
+                    }}
+                /"};
+                slice_views.pop();
+            }
         }
 
         load_diffuse_textures += &formatdoc! {r#"
-            {indent}Texture::from_bytes(
-            {indent}    ctx,
-            {indent}    include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/atlas/diffuse-{page_i}.webp")),
-            {indent}    "Diffuse Texture {page_i}",
-            {indent})?,
+            {indent}include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/atlas/diffuse-{page_i}.webp")),
             "#,
-            indent = "                ",
+            indent = "                    ",
         };
         load_normal_textures += &formatdoc! {r#"
-            {indent}Texture::from_bytes(
-            {indent}    ctx,
-            {indent}    include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/atlas/normal-{page_i}.webp")),
-            {indent}    "Normal Texture {page_i}",
-            {indent})?,
+            {indent}include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/atlas/normal-{page_i}.webp")),
             "#,
-            indent = "                ",
+            indent = "                    ",
         };
     }
 
@@ -172,39 +258,125 @@ fn generate_code<'a>(
     load_diffuse_textures.pop();
     load_normal_textures.pop();
 
+    let clips = animation::find_clips(&names);
+    let mut clip_views = String::with_capacity(128 * clips.len());
+
+    for clip in clips {
+        let frames: Vec<String> = clip
+            .frames
+            .iter()
+            .map(|frame| format!("Sprite::{}", sprite_variant(frame)))
+            .collect();
+        let durations: Vec<String> = clip.durations.iter().map(|d| format!("{d}f32")).collect();
+
+        clip_views += &formatdoc! {"
+            #[allow(dead_code)]
+            pub fn clip_{name}() -> AnimationClip {{
+                AnimationClip {{
+                    frames: &[{frames}],
+                    durations: &[{durations}],
+                    mode: AnimationMode::{mode},
+                }}
+            }}
+        /",
+            name = clip.name,
+            frames = frames.join(", "),
+            durations = durations.join(", "),
+            mode = clip.mode,
+        };
+        clip_views.pop();
+    }
+    clip_views.pop();
+    slice_views.pop();
+
+    let sprites_count = names.len();
+    let mut sprite_variants = String::with_capacity(32 * sprites_count);
+    let mut sprite_all = String::with_capacity(32 * sprites_count);
+    let mut sprite_names = String::with_capacity(32 * sprites_count);
+    let mut sprite_views = String::with_capacity(64 * sprites_count);
+
+    for name in names {
+        let variant = sprite_variant(name);
+        sprite_variants += &format!("    {variant},\n");
+        sprite_all += &format!("        Sprite::{variant},\n");
+        sprite_names += &format!("        \"{name}\",\n");
+        sprite_views += &format!("            Sprite::{variant} => TextureAtlas::view_{name}(),\n");
+    }
+
+    sprite_variants.pop();
+    sprite_all.pop();
+    sprite_names.pop();
+    sprite_views.pop();
+
     let texture_atlas_src = formatdoc! {"
         // THIS CODE IS GENERATED BY THE BUILD SCRIPT.
         // ANY CHANGE WILL BE OVERWRITTEN.
         
+        use crate::animation::*;
         use crate::error::*;
         use crate::math::*;
         use crate::wgpu_components::*;
         
+        pub const MIP_LEVELS: u32 = {ATLAS_MIP_LEVELS};
+
+        /// Every page of the atlas is a layer of the texture arrays
         pub struct TextureAtlas {{
-            pub diffuse_textures: [Texture; {pages_count}],
-            pub normal_textures: [Texture; {pages_count}],
+            pub diffuse_textures: Texture,
+            pub normal_textures: Texture,
         }}
 
         #[derive(Copy, Clone)]
         pub struct TextureAtlasView {{
             pub pos: Vec2,
             pub size: Vec2,
+            pub page: u32,
+            /// Aspect ratio of the untrimmed image
             ratio: f32,
+            /// Center of the trimmed image relative to the untrimmed one (in untrimmed units)
+            pub trim_offset: Vec2,
+            /// Size of the trimmed image relative to the untrimmed one
+            pub trim_scale: Vec2,
         }}
 
         impl TextureAtlas {{
             pub fn load(ctx: &WgpuContext) -> ErrResult<Self> {{
                 Ok(Self {{
-                    diffuse_textures: [
+                    diffuse_textures: Texture::from_bytes_array(
+                        ctx,
+                        &[
         {load_diffuse_textures}
-                    ],
-                    normal_textures: [
+                        ],
+                        MIP_LEVELS,
+                        \"Diffuse Texture Atlas\",
+                    )?,
+                    normal_textures: Texture::from_bytes_array(
+                        ctx,
+                        &[
         {load_normal_textures}
-                    ],
+                        ],
+                        MIP_LEVELS,
+                        \"Normal Texture Atlas\",
+                    )?,
                 }})
             }}
         
         {texture_views}
+        {slice_views}
+        {clip_views}
+        }}
+
+        /// A sprite that stretches the center and keeps the borders
+        #[derive(Copy, Clone)]
+        pub struct NineSliceView {{
+            pub view: TextureAtlasView,
+            /// Left and bottom borders of the trimmed image (in units of the untrimmed height)
+            pub insets_min: Vec2,
+            /// Right and top borders of the trimmed image (in units of the untrimmed height)
+            pub insets_max: Vec2,
+            /// Left and bottom space removed by the trim (in units of the untrimmed height)
+            pub margins_min: Vec2,
+            /// Right and top space removed by the trim (in units of the untrimmed height)
+            pub margins_max: Vec2,
         }}
 
         impl TextureAtlasView {{
@@ -213,11 +385,51 @@ fn generate_code<'a>(
                 Vec2::new(self.ratio, 1.)
             }}
         }}
+
+        /// Every image of the atlas, the discriminant is the sprite id
+        #[allow(dead_code)]
+        #[repr(u16)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum Sprite {{
+        {sprite_variants}
+        }}
+
+        impl Sprite {{
+            /// Sorted by name, indexed by the sprite id
+            pub const ALL: [Sprite; {sprites_count}] = [
+        {sprite_all}
+            ];
+
+            /// Sorted by name, indexed by the sprite id
+            pub const NAMES: [&'static str; {sprites_count}] = [
+        {sprite_names}
+            ];
+
+            pub fn view(self) -> TextureAtlasView {{
+                match self {{
+        {sprite_views}
+                }}
+            }}
+        }}
     "};
 
     fs::write("src/texture_atlas.rs", texture_atlas_src).unwrap();
 }
 
+/// Converts a snake_case asset name to a CamelCase enum variant
+fn sprite_variant(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
 pub fn main() -> Atlas {
     let mut atlas = Atlas::default();
 
@@ -225,9 +437,11 @@ pub fn main() -> Atlas {
     let mut normal = MultiTexturePacker::new_skyline(ATLAS_CONFIG);
 
     pack_images(&mut diffuse, &mut normal);
-    export_textures("diffuse", &mut diffuse);
-    export_textures("normal", &mut normal);
-    generate_code(&mut diffuse, &mut atlas);
+
+    let pages_size = pages_size(&diffuse);
+    export_textures("diffuse", &mut diffuse, pages_size);
+    export_textures("normal", &mut normal, pages_size);
+    generate_code(&mut diffuse, pages_size, &mut atlas);
 
     println!("cargo:rerun-if-changed=assets,atlas");
 
@@ -2,6 +2,7 @@ use super::atlas::*;
 use super::*;
 
 pub struct Font {
+    pub page: u32,
     pub tex_size: Vec2,
     pub line_height: f32,
     pub glyphs: Vec<Option<Glyph>>,
@@ -20,6 +21,7 @@ pub struct Glyph {
 impl Font {
     pub fn parse(fnt_file: &str, texture_view: AtlasView) -> Self {
         let mut font = Font {
+            page: texture_view.page,
             tex_size: Vec2::zero(),
             line_height: 0.,
             glyphs: Vec::with_capacity(128),
@@ -53,9 +55,10 @@ impl Font {
             if let Some(glyph) = glyph {
                 glyph.size = glyph.tex_size;
 
-                glyph.tex_pos *= texture_view.size / self.tex_size;
-                glyph.tex_pos += texture_view.pos;
-                glyph.tex_size *= texture_view.size / self.tex_size;
+                // The glyph coordinates refer to the untrimmed image
+                glyph.tex_pos *= texture_view.source_size / self.tex_size;
+                glyph.tex_pos += texture_view.source_pos;
+                glyph.tex_size *= texture_view.source_size / self.tex_size;
 
                 glyph.pos /= self.line_height;
                 glyph.size /= self.line_height;
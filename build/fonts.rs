@@ -14,6 +14,7 @@ pub fn main(atlas: &Atlas) {
 
 fn generate_code(font: Font) {
     let Font {
+        page,
         tex_size,
         line_height,
         glyphs,
@@ -55,6 +56,7 @@ fn generate_code(font: Font) {
         use super::*;
     
         pub const FONT: Font = Font {{
+            page: {page},
             tex_size: {tex_size},
             line_height: {line_height}f32,
             glyphs: &[\n{glyphs_src}
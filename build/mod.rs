@@ -1,7 +1,10 @@
+mod animation;
 mod atlas;
 mod font_parser;
 mod fonts;
 mod math;
+mod nine_slice;
+mod normal_map;
 mod shapes;
 
 use math::*;
@@ -0,0 +1,36 @@
+use std::fs;
+
+/// Borders of a nine-slice sprite in pixels of the untrimmed image.
+///
+/// Read from `assets/{name}.slice`, which contains: `left top right bottom`
+pub struct Insets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+pub fn read_insets(name: &str) -> Option<Insets> {
+    let path = format!("assets/{name}.slice");
+    let config = fs::read_to_string(&path).ok()?;
+
+    let insets: Vec<f32> = config
+        .split_whitespace()
+        .map(|inset| {
+            inset
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid inset in {path}: {inset}"))
+        })
+        .collect();
+
+    let [left, top, right, bottom] = insets[..] else {
+        panic!("{path} must contain 4 insets: left top right bottom");
+    };
+
+    Some(Insets {
+        left,
+        top,
+        right,
+        bottom,
+    })
+}
@@ -0,0 +1,115 @@
+use image::{DynamicImage, ImageBuffer, RgbaImage};
+
+#[derive(Clone, Copy)]
+pub struct NormalMapConfig {
+    /// Multiplier applied to the slope of the height map
+    pub strength: f32,
+    /// Distance in pixels from the edge until the sprite reaches its full height
+    pub bevel: f32,
+}
+
+/// Generates a tangent space normal map from the height derived of `image`,
+/// the average of its luminance and a bevel rising from its transparent edges.
+/// The alpha channel of `image` is preserved.
+pub fn generate(image: &DynamicImage, config: &NormalMapConfig) -> DynamicImage {
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+
+    let heights: Vec<f32> = luminance_height(&rgba)
+        .into_iter()
+        .zip(edge_height(&rgba, config.bevel))
+        .map(|(luminance, edge)| (luminance + edge) * 0.5)
+        .collect();
+
+    let sample = |x: i64, y: i64| {
+        let x = x.clamp(0, width as i64 - 1) as usize;
+        let y = y.clamp(0, height as i64 - 1) as usize;
+        heights[y * width as usize + x]
+    };
+
+    let normals = ImageBuffer::from_fn(width, height, |x, y| {
+        let (x, y) = (x as i64, y as i64);
+
+        // Sobel operator
+        let dx = (sample(x + 1, y - 1) + 2. * sample(x + 1, y) + sample(x + 1, y + 1))
+            - (sample(x - 1, y - 1) + 2. * sample(x - 1, y) + sample(x - 1, y + 1));
+        let dy = (sample(x - 1, y + 1) + 2. * sample(x, y + 1) + sample(x + 1, y + 1))
+            - (sample(x - 1, y - 1) + 2. * sample(x, y - 1) + sample(x + 1, y - 1));
+
+        // The image y axis points down while the normal y axis points up
+        let normal = [-dx / 8. * config.strength, dy / 8. * config.strength, 1.];
+        let length = normal.iter().map(|n| n * n).sum::<f32>().sqrt();
+
+        let encode = |n: f32| ((n / length * 0.5 + 0.5) * 255.).round() as u8;
+        let alpha = rgba.get_pixel(x as u32, y as u32)[3];
+
+        image::Rgba([
+            encode(normal[0]),
+            encode(normal[1]),
+            encode(normal[2]),
+            alpha,
+        ])
+    });
+
+    DynamicImage::ImageRgba8(normals)
+}
+
+fn luminance_height(rgba: &RgbaImage) -> Vec<f32> {
+    rgba.pixels()
+        .map(|pixel| {
+            let [r, g, b, a] = pixel.0.map(|c| c as f32 / 255.);
+            (0.2126 * r + 0.7152 * g + 0.0722 * b) * a
+        })
+        .collect()
+}
+
+/// Height of a rounded bevel following the distance to the nearest transparent pixel
+fn edge_height(rgba: &RgbaImage, bevel: f32) -> Vec<f32> {
+    let (width, height) = (rgba.width() as usize, rgba.height() as usize);
+
+    // Two pass chamfer distance transform, the image border counts as transparent
+    let mut dist: Vec<f32> = rgba
+        .pixels()
+        .map(|pixel| if pixel[3] < 128 { 0. } else { f32::MAX })
+        .collect();
+
+    let get = |dist: &Vec<f32>, x: isize, y: isize| {
+        if x < 0 || y < 0 || x >= width as isize || y >= height as isize {
+            0.
+        } else {
+            dist[y as usize * width + x as usize]
+        }
+    };
+
+    const STRAIGHT: f32 = 1.;
+    const DIAGONAL: f32 = std::f32::consts::SQRT_2;
+
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            let d = get(&dist, x, y)
+                .min(get(&dist, x - 1, y) + STRAIGHT)
+                .min(get(&dist, x, y - 1) + STRAIGHT)
+                .min(get(&dist, x - 1, y - 1) + DIAGONAL)
+                .min(get(&dist, x + 1, y - 1) + DIAGONAL);
+            dist[y as usize * width + x as usize] = d;
+        }
+    }
+
+    for y in (0..height as isize).rev() {
+        for x in (0..width as isize).rev() {
+            let d = get(&dist, x, y)
+                .min(get(&dist, x + 1, y) + STRAIGHT)
+                .min(get(&dist, x, y + 1) + STRAIGHT)
+                .min(get(&dist, x + 1, y + 1) + DIAGONAL)
+                .min(get(&dist, x - 1, y + 1) + DIAGONAL);
+            dist[y as usize * width + x as usize] = d;
+        }
+    }
+
+    dist.into_iter()
+        .map(|d| {
+            let t = 1. - (d / bevel.max(1.)).min(1.);
+            (1. - t * t).sqrt()
+        })
+        .collect()
+}
@@ -0,0 +1,6 @@
+use wgpu_shadows::run_demo;
+
+#[tokio::main]
+async fn main() {
+    run_demo().await;
+}
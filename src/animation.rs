@@ -0,0 +1,216 @@
+use crate::layers::*;
+use crate::math::*;
+use crate::shaders::*;
+use crate::texture_atlas::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnimationMode {
+    /// Stops at the last frame
+    Once,
+    Loop,
+    /// Plays forward and backward without repeating the end frames
+    PingPong,
+}
+
+/// Generated by the build script from the assets named `{clip}_frame{index}`.
+/// See `TextureAtlas::clip_*`
+#[derive(Copy, Clone, Debug)]
+pub struct AnimationClip {
+    pub frames: &'static [Sprite],
+    /// Seconds that each frame is shown
+    pub durations: &'static [f32],
+    pub mode: AnimationMode,
+}
+
+impl AnimationClip {
+    /// Seconds of a single pass through all the frames
+    pub fn duration(&self) -> f32 {
+        self.durations.iter().sum()
+    }
+
+    /// Index of the frame shown `time` seconds after the start
+    pub fn frame_at(&self, time: f32) -> usize {
+        let last = self.frames.len() - 1;
+        if last == 0 {
+            return 0;
+        }
+
+        let duration = self.duration();
+        let time = match self.mode {
+            AnimationMode::Once if time >= duration => return last,
+            AnimationMode::Once => time,
+            AnimationMode::Loop => time.rem_euclid(duration),
+            AnimationMode::PingPong => {
+                // The way back skips the first and the last frame
+                let back = duration - self.durations[0] - self.durations[last];
+                let time = time.rem_euclid(duration + back);
+                if time >= duration {
+                    let mut time = time - duration;
+                    for frame in (1..last).rev() {
+                        if time < self.durations[frame] {
+                            return frame;
+                        }
+                        time -= self.durations[frame];
+                    }
+                    return 1;
+                }
+                time
+            }
+        };
+
+        let mut time = time;
+        for (frame, duration) in self.durations.iter().enumerate() {
+            if time < *duration {
+                return frame;
+            }
+            time -= duration;
+        }
+        last
+    }
+}
+
+/// A quad that swaps its sprite following an `AnimationClip`.
+/// The normal map follows the diffuse texture, as both atlases share the layout.
+pub struct AnimatedSprite {
+    clip: AnimationClip,
+    quad: usize,
+    pos: Vec2,
+    width: f32,
+    pivot: Vec2,
+    time: f32,
+    frame: usize,
+    /// Multiplier of the elapsed time
+    pub speed: f32,
+    pub paused: bool,
+}
+
+impl AnimatedSprite {
+    pub fn new(layer: &mut QuadLayer, pos: Vec2, width: f32, clip: AnimationClip) -> Self {
+        let quad = layer
+            .buffer
+            .push(QuadInstance::new_tex(pos, width, clip.frames[0].view()));
+
+        Self {
+            clip,
+            quad,
+            pos,
+            width,
+            pivot: Vec2::zero(),
+            time: 0.,
+            frame: 0,
+            speed: 1.,
+            paused: false,
+        }
+    }
+
+    /// Index of the quad in the layer
+    pub fn quad(&self) -> usize {
+        self.quad
+    }
+
+    pub fn clip(&self) -> AnimationClip {
+        self.clip
+    }
+
+    pub fn current_sprite(&self) -> Sprite {
+        self.clip.frames[self.frame]
+    }
+
+    /// Starts playing `clip` from the first frame
+    pub fn play(&mut self, layer: &mut QuadLayer, clip: AnimationClip) {
+        self.clip = clip;
+        self.restart(layer);
+    }
+
+    pub fn restart(&mut self, layer: &mut QuadLayer) {
+        self.time = 0.;
+        self.set_frame(layer, 0);
+    }
+
+    /// Point of the untrimmed frames that stays at `pos`, see `QuadInstance::new_tex_pivot`
+    pub fn set_pivot(&mut self, layer: &mut QuadLayer, pivot: Vec2) {
+        self.pivot = pivot;
+        self.update_quad(layer);
+    }
+
+    /// Advances the animation `delta_time` seconds
+    pub fn update(&mut self, layer: &mut QuadLayer, delta_time: f32) {
+        if self.paused {
+            return;
+        }
+
+        self.time += delta_time * self.speed;
+        let frame = self.clip.frame_at(self.time);
+        if frame != self.frame {
+            self.set_frame(layer, frame);
+        }
+    }
+
+    fn set_frame(&mut self, layer: &mut QuadLayer, frame: usize) {
+        self.frame = frame;
+        self.update_quad(layer);
+    }
+
+    fn update_quad(&mut self, layer: &mut QuadLayer) {
+        let view = self.current_sprite().view();
+        layer
+            .buffer
+            .get_mut(self.quad)
+            .set_texture(self.pos, self.width, view, self.pivot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clip(mode: AnimationMode) -> AnimationClip {
+        AnimationClip {
+            frames: &Sprite::ALL[..3],
+            durations: &[0.1, 0.2, 0.3],
+            mode,
+        }
+    }
+
+    fn frames(clip: &AnimationClip, times: &[f32]) -> Vec<usize> {
+        times.iter().map(|time| clip.frame_at(*time)).collect()
+    }
+
+    #[test]
+    fn loop_wraps_around() {
+        let clip = clip(AnimationMode::Loop);
+        assert_eq!(
+            frames(&clip, &[0., 0.05, 0.15, 0.45, 0.65, 0.75, 1.15]),
+            [0, 0, 1, 2, 0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn ping_pong_skips_the_end_frames_on_the_way_back() {
+        let clip = clip(AnimationMode::PingPong);
+        // Forward in 0.6 seconds, back through the middle frame in 0.2
+        assert_eq!(
+            frames(&clip, &[0.05, 0.25, 0.55, 0.65, 0.75, 0.85, 0.95]),
+            [0, 1, 2, 1, 1, 0, 1]
+        );
+    }
+
+    #[test]
+    fn once_holds_the_last_frame() {
+        let clip = clip(AnimationMode::Once);
+        assert_eq!(
+            frames(&clip, &[0.05, 0.25, 0.55, 0.6, 10.]),
+            [0, 1, 2, 2, 2]
+        );
+    }
+
+    #[test]
+    fn single_frame() {
+        let clip = AnimationClip {
+            frames: &Sprite::ALL[..1],
+            durations: &[0.1],
+            mode: AnimationMode::PingPong,
+        };
+        assert_eq!(frames(&clip, &[0., 0.15, 3.]), [0, 0, 0]);
+    }
+}
@@ -1,10 +1,61 @@
+mod shake;
+
+pub use shake::*;
+
 use crate::input::*;
 use crate::ligth_pipeline::*;
 use crate::math::*;
 use crate::wgpu_components::*;
 
+/// 2D view of the world. The y axis of the screen spans `2 / zoom` world units
 pub struct Camera {
     uniform: CachedUniform<CameraUniform>,
+    view: View,
+    pub follow: Option<CameraFollow>,
+    /// Area of the world that the view does not leave
+    pub bounds: Option<CameraBounds>,
+    /// Only moves the view, the mouse keeps the position of the camera
+    pub shake: CameraShake,
+    /// Uniforms of the layers that don't follow the camera one to one
+    parallax: Vec<(Parallax, CachedUniform<CameraUniform>)>,
+}
+
+/// Placement of the camera, without the shake
+#[derive(Copy, Clone, Debug)]
+struct View {
+    pos: Vec2,
+    zoom: f32,
+    /// Counterclockwise in radians
+    rotation: f32,
+    /// Height / width of the screen
+    aspect: f32,
+}
+
+/// How a layer follows the camera, see `Camera::add_parallax`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Parallax {
+    /// Fraction of the camera movement, 0 stays fixed on the screen
+    pub factor: Vec2,
+    /// Exponent applied to the zoom, 0 keeps the size of the layer
+    pub scale: f32,
+}
+
+/// Moves the camera towards `target` on `Camera::update`
+#[derive(Copy, Clone, Debug)]
+pub struct CameraFollow {
+    pub target: Vec2,
+    /// Half size of the area around the camera where the target moves freely
+    pub deadzone: Vec2,
+    /// Rate at which the distance to the target is closed, per second.
+    /// Zero snaps to the target
+    pub damping: f32,
+}
+
+/// Axis aligned area of the world
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraBounds {
+    pub min: Vec2,
+    pub max: Vec2,
 }
 
 #[repr(C)]
@@ -12,6 +63,63 @@ pub struct Camera {
 struct CameraUniform {
     pub pos: Vec2,
     pub size: Vec2,
+    /// Cosine and sine of the rotation
+    pub rotation: Vec2,
+}
+
+impl CameraUniform {
+    /// Area of the world seen through the uniform
+    fn view_bounds(&self) -> CameraBounds {
+        let half = Vec2::new(1. / self.size.x, 1. / self.size.y);
+        let (cos, sin) = (self.rotation.x.abs(), self.rotation.y.abs());
+        let extents = Vec2::new(cos * half.x + sin * half.y, sin * half.x + cos * half.y);
+        CameraBounds {
+            min: self.pos - extents,
+            max: self.pos + extents,
+        }
+    }
+}
+
+impl View {
+    /// Scale from world to clip space
+    fn size(self) -> Vec2 {
+        Vec2::new(self.aspect, 1.) * self.zoom
+    }
+
+    /// Half size of the axis aligned box around the view, in world units
+    fn half_extents(self) -> Vec2 {
+        let half = Vec2::new(1. / self.aspect, 1.) / self.zoom;
+        let (sin, cos) = self.rotation.sin_cos();
+        Vec2::new(
+            cos.abs() * half.x + sin.abs() * half.y,
+            sin.abs() * half.x + cos.abs() * half.y,
+        )
+    }
+
+    fn project(self, world: Vec2) -> Vec2 {
+        (world - self.pos).rotate(-self.rotation) * self.size()
+    }
+
+    fn transform(self, clip: Vec2) -> Vec2 {
+        (clip / self.size()).rotate(self.rotation) + self.pos
+    }
+
+    /// Closest position that keeps the view inside `bounds`,
+    /// centered on the axes where the bounds are smaller than the view
+    fn clamped_pos(self, bounds: CameraBounds) -> Vec2 {
+        let half = self.half_extents();
+        let clamp = |pos: f32, min: f32, max: f32, half: f32| {
+            if max - min < half * 2. {
+                (min + max) * 0.5
+            } else {
+                pos.clamp(min + half, max - half)
+            }
+        };
+        Vec2::new(
+            clamp(self.pos.x, bounds.min.x, bounds.max.x, half.x),
+            clamp(self.pos.y, bounds.min.y, bounds.max.y, half.y),
+        )
+    }
 }
 
 impl Camera {
@@ -23,28 +131,223 @@ impl Camera {
                 CameraUniform {
                     pos: Vec2::new(0., 0.),
                     size: Vec2::new(1., 1.),
+                    rotation: Vec2::new(1., 0.),
                 },
             ),
+            view: View {
+                pos: Vec2::zero(),
+                zoom: 1.,
+                rotation: 0.,
+                aspect: 1.,
+            },
+            follow: None,
+            bounds: None,
+            shake: CameraShake::new(0),
+            parallax: Vec::new(),
         }
     }
 
+    /// Returns the index of the parallax, for `bind_parallax`
+    pub fn add_parallax(&mut self, ctx: &WgpuContext, parallax: Parallax) -> usize {
+        let uniform = CachedUniform::new(ctx, wgpu::ShaderStages::VERTEX, self.uniform.data);
+        self.parallax.push((parallax, uniform));
+        self.update_uniform();
+        self.parallax.len() - 1
+    }
+
     pub fn resize(&mut self, size: Vec2) {
+        self.view.aspect = size.y / size.x;
+        self.update_uniform();
+    }
+
+    pub fn pos(&self) -> Vec2 {
+        self.view.pos
+    }
+
+    pub fn set_pos(&mut self, pos: Vec2) {
+        self.view.pos = pos;
+        self.update_uniform();
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.view.zoom
+    }
+
+    /// Greater than 1 magnifies the world
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.view.zoom = zoom;
+        self.update_uniform();
+    }
+
+    pub fn rotation(&self) -> f32 {
+        self.view.rotation
+    }
+
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.view.rotation = rotation;
+        self.update_uniform();
+    }
+
+    /// Area of the world on the screen, including the shake
+    pub fn view_bounds(&self) -> CameraBounds {
+        self.uniform.data.view_bounds()
+    }
+
+    /// Area of the world on the screen for the layers with the parallax `index`
+    pub fn parallax_view_bounds(&self, index: usize) -> CameraBounds {
+        self.parallax[index].1.data.view_bounds()
+    }
+
+    /// Follows the target, keeps the view inside the bounds and advances the shake
+    pub fn update(&mut self, delta_time: f32) {
+        if let Some(follow) = self.follow {
+            let offset = follow.target - self.view.pos;
+            let outside = Vec2::new(
+                offset.x - offset.x.clamp(-follow.deadzone.x, follow.deadzone.x),
+                offset.y - offset.y.clamp(-follow.deadzone.y, follow.deadzone.y),
+            );
+
+            let t = if follow.damping > 0. {
+                1. - (-follow.damping * delta_time).exp()
+            } else {
+                1.
+            };
+            self.view.pos += outside * t;
+        }
+
+        self.shake.update(delta_time);
+        self.update_uniform();
+    }
+
+    /// World position to clip space, inverse of `MouseTransform::transform`
+    pub fn project(&self, world: Vec2) -> Vec2 {
+        self.view.project(world)
+    }
+
+    fn update_uniform(&mut self) {
+        if let Some(bounds) = self.bounds {
+            self.view.pos = self.view.clamped_pos(bounds);
+        }
+
+        let (offset, rotation) = self.shake.offset();
+        let rotation = self.view.rotation + rotation;
+
+        let pos = self.view.pos + offset.rotate(rotation);
+
+        let (sin, cos) = rotation.sin_cos();
         self.uniform.update(CameraUniform {
-            pos: Vec2::new(0., 0.),
-            size: Vec2::new(size.y / size.x, 1.),
+            pos,
+            size: self.view.size(),
+            rotation: Vec2::new(cos, sin),
         });
+
+        for (parallax, uniform) in &mut self.parallax {
+            uniform.update(CameraUniform {
+                pos: pos * parallax.factor,
+                size: Vec2::new(self.view.aspect, 1.) * self.view.zoom.powf(parallax.scale),
+                rotation: Vec2::new(cos, sin),
+            });
+        }
     }
 
-    pub fn bind<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>) {
-        self.uniform.update_buffers(pass.context);
+    /// Uploads the changes of the camera and its parallax uniforms
+    pub fn update_buffers(&mut self, ctx: &WgpuContext) {
+        self.uniform.update_buffers(ctx);
+        for (_, uniform) in &mut self.parallax {
+            uniform.update_buffers(ctx);
+        }
+    }
+
+    pub fn bind<'a>(&'a self, pass: &mut LigthRenderPass<'a>) {
         self.uniform.bind(1, &mut pass.normal);
         self.uniform.bind(1, &mut pass.ligth);
+        if let Some(unshadowed) = &mut pass.unshadowed {
+            self.uniform.bind(1, unshadowed);
+        }
         self.uniform.bind(1, &mut pass.diffuse);
     }
+
+    /// Binds a uniform of `add_parallax` instead of the camera one
+    pub fn bind_parallax<'a>(&'a self, index: usize, pass: &mut LigthRenderPass<'a>) {
+        let uniform = &self.parallax[index].1;
+        uniform.bind(1, &mut pass.normal);
+        uniform.bind(1, &mut pass.ligth);
+        if let Some(unshadowed) = &mut pass.unshadowed {
+            uniform.bind(1, unshadowed);
+        }
+        uniform.bind(1, &mut pass.diffuse);
+    }
 }
 
 impl MouseTransform for Camera {
+    /// Clip space to world position
     fn transform(&self, pos: Vec2) -> Vec2 {
-        pos / self.uniform.data.size + self.uniform.data.pos
+        self.view.transform(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!((a - b).length() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    const VIEW: View = View {
+        pos: Vec2::new(3., -1.),
+        zoom: 2.,
+        rotation: 0.7,
+        aspect: 0.5,
+    };
+
+    #[test]
+    fn project_and_transform_round_trip() {
+        for pos in [Vec2::zero(), Vec2::new(1., 0.), Vec2::new(-0.4, 0.9)] {
+            assert_near(VIEW.project(VIEW.transform(pos)), pos);
+            assert_near(VIEW.transform(VIEW.project(pos)), pos);
+        }
+        assert_near(VIEW.project(VIEW.pos), Vec2::zero());
+
+        // The top of the screen is one world unit above the camera at zoom 1
+        let view = View {
+            pos: Vec2::zero(),
+            zoom: 1.,
+            rotation: 0.,
+            aspect: 0.5,
+        };
+        assert_near(view.transform(Vec2::new(1., 1.)), Vec2::new(2., 1.));
+    }
+
+    #[test]
+    fn clamps_the_view_inside_the_bounds() {
+        // 4 by 2 world units
+        let view = |pos| View {
+            pos,
+            zoom: 1.,
+            rotation: 0.,
+            aspect: 0.5,
+        };
+        let bounds = CameraBounds {
+            min: Vec2::new(-5., -5.),
+            max: Vec2::new(5., 5.),
+        };
+
+        let pos = Vec2::new(1., -2.);
+        assert_eq!(view(pos).clamped_pos(bounds), pos);
+        assert_eq!(
+            view(Vec2::new(10., -10.)).clamped_pos(bounds),
+            Vec2::new(3., -4.)
+        );
+
+        // Narrower than the view, centered on x
+        let bounds = CameraBounds {
+            min: Vec2::new(1., -5.),
+            max: Vec2::new(3., 5.),
+        };
+        assert_eq!(
+            view(Vec2::new(10., 10.)).clamped_pos(bounds),
+            Vec2::new(2., 4.)
+        );
     }
 }
@@ -0,0 +1,99 @@
+use crate::math::*;
+
+/// Screen shake driven by trauma, which decays over time.
+/// The shake grows with the square of the trauma, so small hits are subtle
+#[derive(Clone, Debug)]
+pub struct CameraShake {
+    /// From 0 to 1
+    trauma: f32,
+    time: f32,
+    seed: u32,
+    /// Trauma lost each second
+    pub decay: f32,
+    /// Offset at full trauma, in world units along the screen axes
+    pub max_offset: Vec2,
+    /// Rotation at full trauma, in radians
+    pub max_rotation: f32,
+    /// Speed of the noise, in changes per second
+    pub frequency: f32,
+}
+
+impl CameraShake {
+    /// Shakes with the same seed and trauma are identical
+    pub fn new(seed: u32) -> Self {
+        Self {
+            trauma: 0.,
+            time: 0.,
+            seed,
+            decay: 1.2,
+            max_offset: Vec2::new(0.08, 0.08),
+            max_rotation: 0.05,
+            frequency: 15.,
+        }
+    }
+
+    /// Adds to the trauma, saturating at 1
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0., 1.);
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.time += delta_time;
+        self.trauma = (self.trauma - self.decay * delta_time).max(0.);
+    }
+
+    /// Offset and rotation added to the camera
+    pub fn offset(&self) -> (Vec2, f32) {
+        let shake = self.trauma * self.trauma;
+        if shake == 0. {
+            return (Vec2::zero(), 0.);
+        }
+
+        let t = self.time * self.frequency;
+        let offset = Vec2::new(noise(self.seed, 0, t), noise(self.seed, 1, t)) * self.max_offset;
+        let rotation = noise(self.seed, 2, t) * self.max_rotation;
+        (offset * shake, rotation * shake)
+    }
+}
+
+/// Smooth value noise from -1 to 1, `channel` gives independent curves
+fn noise(seed: u32, channel: u32, t: f32) -> f32 {
+    let i = t.floor();
+    let f = t - i;
+    let f = f * f * (3. - 2. * f);
+
+    let a = hash(seed, channel, i as i32);
+    let b = hash(seed, channel, i as i32 + 1);
+    a + (b - a) * f
+}
+
+/// Uniform from -1 to 1
+fn hash(seed: u32, channel: u32, i: i32) -> f32 {
+    let mut x = seed ^ channel.wrapping_mul(0x9E3779B9) ^ (i as u32).wrapping_mul(0x85EBCA6B);
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7FEB352D);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846CA68B);
+    x ^= x >> 16;
+    x as f32 / u32::MAX as f32 * 2. - 1.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_shake() {
+        let mut a = CameraShake::new(7);
+        let mut b = CameraShake::new(7);
+        a.add_trauma(0.8);
+        b.add_trauma(0.8);
+
+        for _ in 0..30 {
+            a.update(1. / 60.);
+            b.update(1. / 60.);
+            assert_eq!(a.offset(), b.offset());
+        }
+        assert_ne!(a.offset(), (Vec2::zero(), 0.));
+    }
+}
@@ -4,639 +4,640 @@
 use super::*;
 
 pub const FONT: Font = Font {
+    page: 0,
     tex_size: Vec2::new(488., 486.),
     line_height: 72f32,
     glyphs: &[
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.),
+            tex_pos: Vec2::new(0.48110697, -0.0003912363),
             tex_size: Vec2::new(0., 0.),
             pos: Vec2::new(0., 0.),
             size: Vec2::new(0., 0.),
             advance: 0.2638889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5856512, 0.052683994),
-            tex_size: Vec2::new(0.0027511094, 0.020444535),
+            tex_pos: Vec2::new(0.6072379, 0.052034426),
+            tex_size: Vec2::new(0.0026609898, 0.020344287),
             pos: Vec2::new(0.06944445, -0.29166666),
             size: Vec2::new(0.1388889, 0.7222222),
             advance: 0.2777778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5099957, 0.17456488),
-            tex_size: Vec2::new(0.006052441, 0.007470119),
+            tex_pos: Vec2::new(0.53406066, 0.17331769),
+            tex_size: Vec2::new(0.0058541773, 0.00743349),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.30555555, 0.2638889),
             advance: 0.4027778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5394326, 0.13682112),
-            tex_size: Vec2::new(0.01458088, 0.020444535),
+            tex_pos: Vec2::new(0.56253326, 0.135759),
+            tex_size: Vec2::new(0.014103245, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.7361111, 0.7222222),
             advance: 0.8333333f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5146726, 0.027128326),
-            tex_size: Vec2::new(0.0112795485, 0.023983013),
+            tex_pos: Vec2::new(0.53858435, 0.02660407),
+            tex_size: Vec2::new(0.010910057, 0.023865415),
             pos: Vec2::new(0.041666668, -0.22222222),
             size: Vec2::new(0.5694444, 0.8472222),
             advance: 0.6666667f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5075197, 0.13682112),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5316658, 0.135759),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.013888889, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.55428857, 0.13682112),
-            tex_size: Vec2::new(0.013480436, 0.020444535),
+            tex_pos: Vec2::new(0.5769026, 0.135759),
+            tex_size: Vec2::new(0.013038849, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.6805556, 0.7222222),
             advance: 0.7361111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.58372545, 0.1655221),
-            tex_size: Vec2::new(0.0027511094, 0.007470119),
+            tex_pos: Vec2::new(0.6053752, 0.16431925),
+            tex_size: Vec2::new(0.0026609898, 0.00743349),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.1388889, 0.2638889),
             advance: 0.22222222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46817884, 0.),
-            tex_size: Vec2::new(0.0057773297, 0.025948834),
+            tex_pos: Vec2::new(0.49361363, -0.0003912363),
+            tex_size: Vec2::new(0.0055880784, 0.025821596),
             pos: Vec2::new(0.041666668, -0.2361111),
             size: Vec2::new(0.29166666, 0.9166667),
             advance: 0.3472222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47423127, 0.),
-            tex_size: Vec2::new(0.0057773297, 0.025948834),
+            tex_pos: Vec2::new(0.4994678, -0.0003912363),
+            tex_size: Vec2::new(0.0055880784, 0.025821596),
             pos: Vec2::new(0., -0.2361111),
             size: Vec2::new(0.29166666, 0.9166667),
             advance: 0.3472222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47890815, 0.17456488),
-            tex_size: Vec2::new(0.009628883, 0.013760745),
+            tex_pos: Vec2::new(0.5039915, 0.17331769),
+            tex_size: Vec2::new(0.009313464, 0.01369327),
             pos: Vec2::new(0.013888889, -0.29166666),
             size: Vec2::new(0.4861111, 0.4861111),
             advance: 0.5277778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46542773, 0.17456488),
-            tex_size: Vec2::new(0.010729327, 0.015726566),
+            tex_pos: Vec2::new(0.49095264, 0.17331769),
+            tex_size: Vec2::new(0.01037786, 0.015649453),
             pos: Vec2::new(0.027777778, -0.4027778),
             size: Vec2::new(0.5416667, 0.5555556),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.58372545, 0.15765882),
-            tex_size: Vec2::new(0.0035764421, 0.007470119),
+            tex_pos: Vec2::new(0.6053752, 0.15649453),
+            tex_size: Vec2::new(0.0034592866, 0.00743349),
             pos: Vec2::new(0.013888889, -0.8888889),
             size: Vec2::new(0.18055555, 0.2638889),
             advance: 0.2638889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.57932365, 0.15372717),
-            tex_size: Vec2::new(0.0057773297, 0.0035384772),
+            tex_pos: Vec2::new(0.6011176, 0.15258217),
+            tex_size: Vec2::new(0.0055880784, 0.0035211267),
             pos: Vec2::new(0.027777778, -0.625),
             size: Vec2::new(0.29166666, 0.125),
             advance: 0.3472222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5853761, 0.15372717),
-            tex_size: Vec2::new(0.0024759984, 0.0035384772),
+            tex_pos: Vec2::new(0.6069718, 0.15258217),
+            tex_size: Vec2::new(0.0023948906, 0.0035211267),
             pos: Vec2::new(0.06944445, -0.8888889),
             size: Vec2::new(0.125, 0.125),
             advance: 0.2638889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5303539, 0.13682112),
-            tex_size: Vec2::new(0.00880355, 0.020444535),
+            tex_pos: Vec2::new(0.553752, 0.135759),
+            tex_size: Vec2::new(0.008515167, 0.020344287),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.44444445, 0.7222222),
             advance: 0.44444445f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.52897835, 0.052683994),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5524215, 0.052034426),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.540533, 0.052683994),
-            tex_size: Vec2::new(0.0074279956, 0.020444535),
+            tex_pos: Vec2::new(0.5635977, 0.052034426),
+            tex_size: Vec2::new(0.007184672, 0.020344287),
             pos: Vec2::new(0.027777778, -0.29166666),
             size: Vec2::new(0.375, 0.7222222),
             advance: 0.43055555f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.54823613, 0.052683994),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.57104844, 0.052034426),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6666667f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5597908, 0.052683994),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5822246, 0.052034426),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.013888889, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.57134545, 0.052683994),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5934007, 0.052034426),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.027777778, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.07430802),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.48110697, 0.07355242),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6666667f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46680328, 0.07430802),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.49228314, 0.07355242),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47835794, 0.07430802),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5034593, 0.07355242),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.027777778, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.4899126, 0.07430802),
-            tex_size: Vec2::new(0.01182977, 0.020444535),
+            tex_pos: Vec2::new(0.51463544, 0.07355242),
+            tex_size: Vec2::new(0.011442256, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5972222, 0.7222222),
             advance: 0.6805556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5020175, 0.07430802),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5263438, 0.07355242),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47643217, 0.17456488),
-            tex_size: Vec2::new(0.0022008875, 0.013760745),
+            tex_pos: Vec2::new(0.50159657, 0.17331769),
+            tex_size: Vec2::new(0.0021287918, 0.01369327),
             pos: Vec2::new(0.06944445, -0.5277778),
             size: Vec2::new(0.11111111, 0.4861111),
             advance: 0.2638889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.585101, 0.11598342),
-            tex_size: Vec2::new(0.0035764421, 0.017692387),
+            tex_pos: Vec2::new(0.60670567, 0.11502347),
+            tex_size: Vec2::new(0.0034592866, 0.017605634),
             pos: Vec2::new(0., -0.5277778),
             size: Vec2::new(0.18055555, 0.625),
             advance: 0.2638889f32,
         }),
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.49899125, 0.17456488),
-            tex_size: Vec2::new(0.010729327, 0.009042775),
+            tex_pos: Vec2::new(0.5234167, 0.17331769),
+            tex_size: Vec2::new(0.01037786, 0.008998435),
             pos: Vec2::new(0.027777778, -0.5277778),
             size: Vec2::new(0.5416667, 0.31944445),
             advance: 0.6111111f32,
         }),
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5190744, 0.13682112),
-            tex_size: Vec2::new(0.011004438, 0.020444535),
+            tex_pos: Vec2::new(0.5428419, 0.135759),
+            tex_size: Vec2::new(0.010643959, 0.020344287),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.5555556, 0.7222222),
             advance: 0.5555556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5003668, 0.027128326),
-            tex_size: Vec2::new(0.014030658, 0.024376176),
+            tex_pos: Vec2::new(0.5247472, 0.02660407),
+            tex_size: Vec2::new(0.013571047, 0.02425665),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.7083333, 0.8611111),
             advance: 0.8055556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.48606104, 0.095145725),
-            tex_size: Vec2::new(0.012655104, 0.020444535),
+            tex_pos: Vec2::new(0.51091003, 0.09428795),
+            tex_size: Vec2::new(0.012240552, 0.020344287),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.6388889, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.49899125, 0.095145725),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5234167, 0.09428795),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5105459, 0.095145725),
-            tex_size: Vec2::new(0.009903993, 0.020444535),
+            tex_pos: Vec2::new(0.53459287, 0.09428795),
+            tex_size: Vec2::new(0.009579563, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5, 0.7222222),
             advance: 0.5555556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.520725, 0.095145725),
-            tex_size: Vec2::new(0.011004438, 0.020444535),
+            tex_pos: Vec2::new(0.54443854, 0.09428795),
+            tex_size: Vec2::new(0.010643959, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5555556, 0.7222222),
             advance: 0.6388889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5320046, 0.095145725),
-            tex_size: Vec2::new(0.010454216, 0.020444535),
+            tex_pos: Vec2::new(0.5553486, 0.09428795),
+            tex_size: Vec2::new(0.010111761, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5277778, 0.7222222),
             advance: 0.5833333f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5427339, 0.095145725),
-            tex_size: Vec2::new(0.010179105, 0.020444535),
+            tex_pos: Vec2::new(0.56572646, 0.09428795),
+            tex_size: Vec2::new(0.009845662, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5138889, 0.7222222),
             advance: 0.5833333f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.55318815, 0.095145725),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.5758382, 0.09428795),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5647428, 0.095145725),
-            tex_size: Vec2::new(0.011554659, 0.020444535),
+            tex_pos: Vec2::new(0.5870144, 0.09428795),
+            tex_size: Vec2::new(0.011176157, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5833333, 0.7222222),
             advance: 0.6805556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5212753, 0.052683994),
-            tex_size: Vec2::new(0.0074279956, 0.0208377),
+            tex_pos: Vec2::new(0.54497075, 0.052034426),
+            tex_size: Vec2::new(0.007184672, 0.020735525),
             pos: Vec2::new(0.027777778, -0.2777778),
             size: Vec2::new(0.375, 0.7361111),
             advance: 0.43055555f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.11598342),
-            tex_size: Vec2::new(0.010454216, 0.020444535),
+            tex_pos: Vec2::new(0.48110697, 0.11502347),
+            tex_size: Vec2::new(0.010111761, 0.020344287),
             pos: Vec2::new(0.027777778, -0.29166666),
             size: Vec2::new(0.5277778, 0.7222222),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46597794, 0.11598342),
-            tex_size: Vec2::new(0.01182977, 0.020444535),
+            tex_pos: Vec2::new(0.49148482, 0.11502347),
+            tex_size: Vec2::new(0.011442256, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5972222, 0.7222222),
             advance: 0.6388889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47808284, 0.11598342),
-            tex_size: Vec2::new(0.010729327, 0.020444535),
+            tex_pos: Vec2::new(0.5031932, 0.11502347),
+            tex_size: Vec2::new(0.01037786, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5416667, 0.7222222),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.48908728, 0.11598342),
-            tex_size: Vec2::new(0.013755547, 0.020444535),
+            tex_pos: Vec2::new(0.51383716, 0.11502347),
+            tex_size: Vec2::new(0.013304949, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.6944444, 0.7222222),
             advance: 0.7916667f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5031179, 0.11598342),
-            tex_size: Vec2::new(0.01182977, 0.020444535),
+            tex_pos: Vec2::new(0.5274082, 0.11502347),
+            tex_size: Vec2::new(0.011442256, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5972222, 0.7222222),
             advance: 0.6805556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5152228, 0.11598342),
-            tex_size: Vec2::new(0.01182977, 0.020444535),
+            tex_pos: Vec2::new(0.53911656, 0.11502347),
+            tex_size: Vec2::new(0.011442256, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5972222, 0.7222222),
             advance: 0.6805556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5273277, 0.11598342),
-            tex_size: Vec2::new(0.011004438, 0.020444535),
+            tex_pos: Vec2::new(0.5508249, 0.11502347),
+            tex_size: Vec2::new(0.010643959, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5555556, 0.7222222),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.52622724, 0.027128326),
-            tex_size: Vec2::new(0.012655104, 0.02280352),
+            tex_pos: Vec2::new(0.5497605, 0.02660407),
+            tex_size: Vec2::new(0.012240552, 0.022691706),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.6388889, 0.8055556),
             advance: 0.6805556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.53860724, 0.11598342),
-            tex_size: Vec2::new(0.012104882, 0.020444535),
+            tex_pos: Vec2::new(0.561735, 0.11502347),
+            tex_size: Vec2::new(0.011708355, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.6111111, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.55098724, 0.11598342),
-            tex_size: Vec2::new(0.011004438, 0.020444535),
+            tex_pos: Vec2::new(0.5737094, 0.11502347),
+            tex_size: Vec2::new(0.010643959, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5555556, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.56226677, 0.11598342),
-            tex_size: Vec2::new(0.011004438, 0.020444535),
+            tex_pos: Vec2::new(0.58461946, 0.11502347),
+            tex_size: Vec2::new(0.010643959, 0.020344287),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.5555556, 0.7222222),
             advance: 0.5694444f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.57354635, 0.11598342),
-            tex_size: Vec2::new(0.0112795485, 0.020444535),
+            tex_pos: Vec2::new(0.59552956, 0.11502347),
+            tex_size: Vec2::new(0.010910057, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5694444, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.13682112),
-            tex_size: Vec2::new(0.012655104, 0.020444535),
+            tex_pos: Vec2::new(0.48110697, 0.135759),
+            tex_size: Vec2::new(0.012240552, 0.020344287),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.6388889, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46817884, 0.13682112),
-            tex_size: Vec2::new(0.016781768, 0.020444535),
+            tex_pos: Vec2::new(0.49361363, 0.135759),
+            tex_size: Vec2::new(0.016232038, 0.020344287),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.8472222, 0.7222222),
             advance: 0.8611111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.57657254, 0.095145725),
-            tex_size: Vec2::new(0.012930214, 0.020444535),
+            tex_pos: Vec2::new(0.5984566, 0.09428795),
+            tex_size: Vec2::new(0.012506652, 0.020344287),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.6527778, 0.7222222),
             advance: 0.6666667f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.48523572, 0.13682112),
-            tex_size: Vec2::new(0.012104882, 0.020444535),
+            tex_pos: Vec2::new(0.51011175, 0.135759),
+            tex_size: Vec2::new(0.011708355, 0.020344287),
             pos: Vec2::new(0.013888889, -0.29166666),
             size: Vec2::new(0.6111111, 0.7222222),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.4976157, 0.13682112),
-            tex_size: Vec2::new(0.009628883, 0.020444535),
+            tex_pos: Vec2::new(0.5220862, 0.135759),
+            tex_size: Vec2::new(0.009313464, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.4861111, 0.7222222),
             advance: 0.5694444f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.49679038, 0.),
-            tex_size: Vec2::new(0.0057773297, 0.025948834),
+            tex_pos: Vec2::new(0.5212879, -0.0003912363),
+            tex_size: Vec2::new(0.0055880784, 0.025821596),
             pos: Vec2::new(0.041666668, -0.2361111),
             size: Vec2::new(0.29166666, 0.9166667),
             advance: 0.3472222f32,
         }),
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.50284284, 0.),
-            tex_size: Vec2::new(0.0057773297, 0.025948834),
+            tex_pos: Vec2::new(0.5271421, -0.0003912363),
+            tex_size: Vec2::new(0.0055880784, 0.025821596),
             pos: Vec2::new(0., -0.2361111),
             size: Vec2::new(0.29166666, 0.9166667),
             advance: 0.3472222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.48881215, 0.17456488),
-            tex_size: Vec2::new(0.009903993, 0.010615432),
+            tex_pos: Vec2::new(0.513571, 0.17331769),
+            tex_size: Vec2::new(0.009579563, 0.01056338),
             pos: Vec2::new(0., -0.29166666),
             size: Vec2::new(0.5, 0.375),
             advance: 0.5138889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.51632327, 0.17456488),
-            tex_size: Vec2::new(0.011004438, 0.0031453131),
+            tex_pos: Vec2::new(0.5401809, 0.17331769),
+            tex_size: Vec2::new(0.010643959, 0.0031298904),
             pos: Vec2::new(0.027777778, -0.9861111),
             size: Vec2::new(0.5555556, 0.11111111),
             advance: 0.6111111f32,
         }),
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.57932365, 0.13682112),
-            tex_size: Vec2::new(0.010179105, 0.016512893),
+            tex_pos: Vec2::new(0.6011176, 0.135759),
+            tex_size: Vec2::new(0.009845662, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5138889, 0.5833333),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.51357216, 0.07430802),
-            tex_size: Vec2::new(0.010729327, 0.020444535),
+            tex_pos: Vec2::new(0.53751993, 0.07355242),
+            tex_size: Vec2::new(0.01037786, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5416667, 0.7222222),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.15765882),
-            tex_size: Vec2::new(0.009628883, 0.016512893),
+            tex_pos: Vec2::new(0.48110697, 0.15649453),
+            tex_size: Vec2::new(0.009313464, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.4861111, 0.5833333),
             advance: 0.5555556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5245766, 0.07430802),
-            tex_size: Vec2::new(0.010454216, 0.020444535),
+            tex_pos: Vec2::new(0.5481639, 0.07355242),
+            tex_size: Vec2::new(0.010111761, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5277778, 0.7222222),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46515262, 0.15765882),
-            tex_size: Vec2::new(0.010179105, 0.016512893),
+            tex_pos: Vec2::new(0.49068654, 0.15649453),
+            tex_size: Vec2::new(0.009845662, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5138889, 0.5833333),
             advance: 0.5972222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5353059, 0.07430802),
-            tex_size: Vec2::new(0.0071528843, 0.020444535),
+            tex_pos: Vec2::new(0.5585418, 0.07355242),
+            tex_size: Vec2::new(0.0069185733, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.3611111, 0.7222222),
             advance: 0.41666666f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5427339, 0.07430802),
-            tex_size: Vec2::new(0.010454216, 0.020444535),
+            tex_pos: Vec2::new(0.56572646, 0.07355242),
+            tex_size: Vec2::new(0.010111761, 0.020344287),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5277778, 0.7222222),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5534632, 0.07430802),
-            tex_size: Vec2::new(0.010454216, 0.020444535),
+            tex_pos: Vec2::new(0.5761043, 0.07355242),
+            tex_size: Vec2::new(0.010111761, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5277778, 0.7222222),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.51852417, 0.052683994),
-            tex_size: Vec2::new(0.0024759984, 0.0208377),
+            tex_pos: Vec2::new(0.54230976, 0.052034426),
+            tex_size: Vec2::new(0.0023948906, 0.020735525),
             pos: Vec2::new(0.041666668, -0.2777778),
             size: Vec2::new(0.125, 0.7361111),
             advance: 0.22222222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.4976157, 0.027128326),
-            tex_size: Vec2::new(0.0024759984, 0.024769342),
+            tex_pos: Vec2::new(0.5220862, 0.02660407),
+            tex_size: Vec2::new(0.0023948906, 0.024647888),
             pos: Vec2::new(0.041666668, -0.2777778),
             size: Vec2::new(0.125, 0.875),
             advance: 0.22222222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.56419253, 0.07430802),
-            tex_size: Vec2::new(0.011004438, 0.020444535),
+            tex_pos: Vec2::new(0.58648217, 0.07355242),
+            tex_size: Vec2::new(0.010643959, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5555556, 0.7222222),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5829001, 0.052683994),
-            tex_size: Vec2::new(0.0024759984, 0.020444535),
+            tex_pos: Vec2::new(0.6045769, 0.052034426),
+            tex_size: Vec2::new(0.0023948906, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.125, 0.7222222),
             advance: 0.22222222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47560683, 0.15765882),
-            tex_size: Vec2::new(0.016506657, 0.016512893),
+            tex_pos: Vec2::new(0.5007983, 0.15649453),
+            tex_size: Vec2::new(0.015965939, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.8333333, 0.5833333),
             advance: 0.9305556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.4923886, 0.15765882),
-            tex_size: Vec2::new(0.010454216, 0.016512893),
+            tex_pos: Vec2::new(0.51703036, 0.15649453),
+            tex_size: Vec2::new(0.010111761, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5277778, 0.5833333),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5031179, 0.15765882),
-            tex_size: Vec2::new(0.010179105, 0.016512893),
+            tex_pos: Vec2::new(0.5274082, 0.15649453),
+            tex_size: Vec2::new(0.009845662, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5138889, 0.5833333),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5754721, 0.07430802),
-            tex_size: Vec2::new(0.010729327, 0.020444535),
+            tex_pos: Vec2::new(0.5973922, 0.07355242),
+            tex_size: Vec2::new(0.01037786, 0.020344287),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5416667, 0.7222222),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.095145725),
-            tex_size: Vec2::new(0.010454216, 0.020444535),
+            tex_pos: Vec2::new(0.48110697, 0.09428795),
+            tex_size: Vec2::new(0.010111761, 0.020344287),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5277778, 0.7222222),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.51357216, 0.15765882),
-            tex_size: Vec2::new(0.008528439, 0.016512893),
+            tex_pos: Vec2::new(0.53751993, 0.15649453),
+            tex_size: Vec2::new(0.008249068, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.43055555, 0.5833333),
             advance: 0.4722222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5223757, 0.15765882),
-            tex_size: Vec2::new(0.010179105, 0.016512893),
+            tex_pos: Vec2::new(0.5460351, 0.15649453),
+            tex_size: Vec2::new(0.009845662, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5138889, 0.5833333),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46597794, 0.095145725),
-            tex_size: Vec2::new(0.0077031064, 0.020444535),
+            tex_pos: Vec2::new(0.49148482, 0.09428795),
+            tex_size: Vec2::new(0.007450771, 0.020344287),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.3888889, 0.7222222),
             advance: 0.43055555f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5328299, 0.15765882),
-            tex_size: Vec2::new(0.010454216, 0.016512893),
+            tex_pos: Vec2::new(0.55614686, 0.15649453),
+            tex_size: Vec2::new(0.010111761, 0.016431924),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.5277778, 0.5833333),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.54355925, 0.15765882),
-            tex_size: Vec2::new(0.011554659, 0.016512893),
+            tex_pos: Vec2::new(0.56652474, 0.15649453),
+            tex_size: Vec2::new(0.011176157, 0.016431924),
             pos: Vec2::new(0., -0.43055555),
             size: Vec2::new(0.5833333, 0.5833333),
             advance: 0.5833333f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.555389, 0.15765882),
-            tex_size: Vec2::new(0.015681325, 0.016512893),
+            tex_pos: Vec2::new(0.577967, 0.15649453),
+            tex_size: Vec2::new(0.015167641, 0.016431924),
             pos: Vec2::new(0.027777778, -0.43055555),
             size: Vec2::new(0.7916667, 0.5833333),
             advance: 0.8472222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.57134545, 0.15765882),
-            tex_size: Vec2::new(0.012104882, 0.016512893),
+            tex_pos: Vec2::new(0.5934007, 0.15649453),
+            tex_size: Vec2::new(0.011708355, 0.016431924),
             pos: Vec2::new(0.013888889, -0.43055555),
             size: Vec2::new(0.6111111, 0.5833333),
             advance: 0.6388889f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47395617, 0.095145725),
-            tex_size: Vec2::new(0.01182977, 0.020444535),
+            tex_pos: Vec2::new(0.4992017, 0.09428795),
+            tex_size: Vec2::new(0.011442256, 0.020344287),
             pos: Vec2::new(0., -0.43055555),
             size: Vec2::new(0.5972222, 0.7222222),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.17456488),
-            tex_size: Vec2::new(0.009903993, 0.016512893),
+            tex_pos: Vec2::new(0.48110697, 0.17331769),
+            tex_size: Vec2::new(0.009579563, 0.016431924),
             pos: Vec2::new(0.013888889, -0.43055555),
             size: Vec2::new(0.5, 0.5833333),
             advance: 0.5416667f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.4802837, 0.),
-            tex_size: Vec2::new(0.007978218, 0.025948834),
+            tex_pos: Vec2::new(0.505322, -0.0003912363),
+            tex_size: Vec2::new(0.00771687, 0.025821596),
             pos: Vec2::new(0., -0.2361111),
             size: Vec2::new(0.4027778, 0.9166667),
             advance: 0.4027778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.),
-            tex_size: Vec2::new(0.0024759984, 0.026735162),
+            tex_pos: Vec2::new(0.48110697, -0.0003912363),
+            tex_size: Vec2::new(0.0023948906, 0.02660407),
             pos: Vec2::new(0.041666668, -0.22222222),
             size: Vec2::new(0.125, 0.9444444),
             advance: 0.22222222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.48853704, 0.),
-            tex_size: Vec2::new(0.007978218, 0.025948834),
+            tex_pos: Vec2::new(0.51330495, -0.0003912363),
+            tex_size: Vec2::new(0.00771687, 0.025821596),
             pos: Vec2::new(0., -0.2361111),
             size: Vec2::new(0.4027778, 0.9166667),
             advance: 0.4027778f32,
@@ -677,8 +678,8 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.58647656, 0.07430802),
-            tex_size: Vec2::new(0.0027511094, 0.020444535),
+            tex_pos: Vec2::new(0.60803616, 0.07355242),
+            tex_size: Vec2::new(0.0026609898, 0.020344287),
             pos: Vec2::new(0.06944445, -0.43055555),
             size: Vec2::new(0.1388889, 0.7222222),
             advance: 0.2777778f32,
@@ -713,22 +714,22 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5680441, 0.13682112),
-            tex_size: Vec2::new(0.011004438, 0.020444535),
+            tex_pos: Vec2::new(0.5902076, 0.135759),
+            tex_size: Vec2::new(0.010643959, 0.020344287),
             pos: Vec2::new(0., -0.43055555),
             size: Vec2::new(0.5555556, 0.7222222),
             advance: 0.5555556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.52100015, 0.),
-            tex_size: Vec2::new(0.012655104, 0.025162505),
+            tex_pos: Vec2::new(0.5447046, -0.0003912363),
+            tex_size: Vec2::new(0.012240552, 0.025039123),
             pos: Vec2::new(0., -0.125),
             size: Vec2::new(0.6388889, 0.8888889),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.57629746, 0.),
-            tex_size: Vec2::new(0.012655104, 0.025162505),
+            tex_pos: Vec2::new(0.59819055, -0.0003912363),
+            tex_size: Vec2::new(0.012240552, 0.025039123),
             pos: Vec2::new(0., -0.125),
             size: Vec2::new(0.6388889, 0.8888889),
             advance: 0.6527778f32,
@@ -739,22 +740,22 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.45799974, 0.),
-            tex_size: Vec2::new(0.009903993, 0.026341997),
+            tex_pos: Vec2::new(0.48376796, -0.0003912363),
+            tex_size: Vec2::new(0.009579563, 0.026212832),
             pos: Vec2::new(0.041666668, -0.29166666),
             size: Vec2::new(0.5, 0.9305556),
             advance: 0.5555556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.53393036, 0.),
-            tex_size: Vec2::new(0.010454216, 0.025162505),
+            tex_pos: Vec2::new(0.5572113, -0.0003912363),
+            tex_size: Vec2::new(0.010111761, 0.025039123),
             pos: Vec2::new(0.041666668, -0.125),
             size: Vec2::new(0.5277778, 0.8888889),
             advance: 0.5833333f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.027128326),
-            tex_size: Vec2::new(0.010454216, 0.025162505),
+            tex_pos: Vec2::new(0.48110697, 0.02660407),
+            tex_size: Vec2::new(0.010111761, 0.025039123),
             pos: Vec2::new(0.041666668, -0.125),
             size: Vec2::new(0.5277778, 0.8888889),
             advance: 0.5833333f32,
@@ -762,15 +763,15 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5446597, 0.),
-            tex_size: Vec2::new(0.0077031064, 0.025162505),
+            tex_pos: Vec2::new(0.56758916, -0.0003912363),
+            tex_size: Vec2::new(0.007450771, 0.025039123),
             pos: Vec2::new(0.013888889, -0.125),
             size: Vec2::new(0.3888889, 0.8888889),
             advance: 0.43055555f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.46597794, 0.027128326),
-            tex_size: Vec2::new(0.0077031064, 0.025162505),
+            tex_pos: Vec2::new(0.49148482, 0.02660407),
+            tex_size: Vec2::new(0.007450771, 0.025039123),
             pos: Vec2::new(0.027777778, -0.125),
             size: Vec2::new(0.3888889, 0.8888889),
             advance: 0.43055555f32,
@@ -779,22 +780,22 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5088953, 0.),
-            tex_size: Vec2::new(0.01182977, 0.025948834),
+            tex_pos: Vec2::new(0.5329963, -0.0003912363),
+            tex_size: Vec2::new(0.011442256, 0.025821596),
             pos: Vec2::new(0.041666668, -0.097222224),
             size: Vec2::new(0.5972222, 0.9166667),
             advance: 0.6805556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5526379, 0.),
-            tex_size: Vec2::new(0.01182977, 0.025162505),
+            tex_pos: Vec2::new(0.575306, -0.0003912363),
+            tex_size: Vec2::new(0.011442256, 0.025039123),
             pos: Vec2::new(0.041666668, -0.125),
             size: Vec2::new(0.5972222, 0.8888889),
             advance: 0.6805556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47395617, 0.027128326),
-            tex_size: Vec2::new(0.01182977, 0.025162505),
+            tex_pos: Vec2::new(0.4992017, 0.02660407),
+            tex_size: Vec2::new(0.011442256, 0.025039123),
             pos: Vec2::new(0.041666668, -0.125),
             size: Vec2::new(0.5972222, 0.8888889),
             advance: 0.6805556f32,
@@ -805,15 +806,15 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5647428, 0.),
-            tex_size: Vec2::new(0.0112795485, 0.025162505),
+            tex_pos: Vec2::new(0.5870144, -0.0003912363),
+            tex_size: Vec2::new(0.010910057, 0.025039123),
             pos: Vec2::new(0.041666668, -0.125),
             size: Vec2::new(0.5694444, 0.8888889),
             advance: 0.6527778f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.48606104, 0.027128326),
-            tex_size: Vec2::new(0.0112795485, 0.025162505),
+            tex_pos: Vec2::new(0.51091003, 0.02660407),
+            tex_size: Vec2::new(0.010910057, 0.025039123),
             pos: Vec2::new(0.041666668, -0.125),
             size: Vec2::new(0.5694444, 0.8888889),
             advance: 0.6527778f32,
@@ -824,15 +825,15 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5597908, 0.027128326),
-            tex_size: Vec2::new(0.010179105, 0.021230863),
+            tex_pos: Vec2::new(0.5822246, 0.02660407),
+            tex_size: Vec2::new(0.009845662, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5138889, 0.75),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.47643217, 0.052683994),
-            tex_size: Vec2::new(0.010179105, 0.021230863),
+            tex_pos: Vec2::new(0.50159657, 0.052034426),
+            tex_size: Vec2::new(0.009845662, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5138889, 0.75),
             advance: 0.6111111f32,
@@ -843,22 +844,22 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.53915745, 0.027128326),
-            tex_size: Vec2::new(0.009628883, 0.022410356),
+            tex_pos: Vec2::new(0.5622672, 0.02660407),
+            tex_size: Vec2::new(0.009313464, 0.022300469),
             pos: Vec2::new(0.041666668, -0.43055555),
             size: Vec2::new(0.4861111, 0.7916667),
             advance: 0.5555556f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.570245, 0.027128326),
-            tex_size: Vec2::new(0.010179105, 0.021230863),
+            tex_pos: Vec2::new(0.59233636, 0.02660407),
+            tex_size: Vec2::new(0.009845662, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5138889, 0.75),
             advance: 0.5972222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.48688638, 0.052683994),
-            tex_size: Vec2::new(0.010179105, 0.021230863),
+            tex_pos: Vec2::new(0.5117084, 0.052034426),
+            tex_size: Vec2::new(0.009845662, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5138889, 0.75),
             advance: 0.5972222f32,
@@ -866,15 +867,15 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5806992, 0.027128326),
-            tex_size: Vec2::new(0.0038515532, 0.021230863),
+            tex_pos: Vec2::new(0.6024481, 0.02660407),
+            tex_size: Vec2::new(0.0037253855, 0.02112676),
             pos: Vec2::new(-0.013888889, -0.2638889),
             size: Vec2::new(0.19444445, 0.75),
             advance: 0.22222222f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5848259, 0.027128326),
-            tex_size: Vec2::new(0.0038515532, 0.021230863),
+            tex_pos: Vec2::new(0.6064396, 0.02660407),
+            tex_size: Vec2::new(0.0037253855, 0.02112676),
             pos: Vec2::new(0.027777778, -0.2638889),
             size: Vec2::new(0.19444445, 0.75),
             advance: 0.22222222f32,
@@ -883,22 +884,22 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.5490615, 0.027128326),
-            tex_size: Vec2::new(0.010454216, 0.022017192),
+            tex_pos: Vec2::new(0.5718467, 0.02660407),
+            tex_size: Vec2::new(0.010111761, 0.021909233),
             pos: Vec2::new(0.041666668, -0.2361111),
             size: Vec2::new(0.5277778, 0.7777778),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.45524862, 0.052683994),
-            tex_size: Vec2::new(0.010179105, 0.021230863),
+            tex_pos: Vec2::new(0.48110697, 0.052034426),
+            tex_size: Vec2::new(0.009845662, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5138889, 0.75),
             advance: 0.6111111f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.4973406, 0.052683994),
-            tex_size: Vec2::new(0.010179105, 0.021230863),
+            tex_pos: Vec2::new(0.5218201, 0.052034426),
+            tex_size: Vec2::new(0.009845662, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5138889, 0.75),
             advance: 0.6111111f32,
@@ -909,15 +910,15 @@ pub const FONT: Font = Font {
         None,
         None,
         Some(Glyph {
-            tex_pos: Vec2::new(0.46570283, 0.052683994),
-            tex_size: Vec2::new(0.010454216, 0.021230863),
+            tex_pos: Vec2::new(0.49121872, 0.052034426),
+            tex_size: Vec2::new(0.010111761, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5277778, 0.75),
             advance: 0.625f32,
         }),
         Some(Glyph {
-            tex_pos: Vec2::new(0.5077948, 0.052683994),
-            tex_size: Vec2::new(0.010454216, 0.021230863),
+            tex_pos: Vec2::new(0.5319319, 0.052034426),
+            tex_size: Vec2::new(0.010111761, 0.02112676),
             pos: Vec2::new(0.041666668, -0.2638889),
             size: Vec2::new(0.5277778, 0.75),
             advance: 0.625f32,
@@ -5,6 +5,7 @@ use crate::math::*;
 use crate::shaders::*;
 
 pub struct Font {
+    page: u32,
     tex_size: Vec2,
     line_height: f32,
     glyphs: &'static [Option<Glyph>],
@@ -58,6 +59,12 @@ impl Font {
                     angle: 0.,
                     tex_pos: glyph.tex_pos,
                     tex_size: glyph.tex_size,
+                    page: self.page,
+                    pivot: Vec2::zero(),
+                    flags: QuadInstance::LIT,
+                    tint: QuadInstance::NO_TINT,
+                    flash: 0,
+                    tiles: Vec2::new(1., 1.),
                 };
 
                 pos.x += glyph.advance * scale;
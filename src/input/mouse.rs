@@ -43,8 +43,8 @@ impl Mouse {
     }
 
     pub fn resize(&mut self, mut size: Vec2) {
-        size.x = size.x * 0.5;
-        size.y = size.y * 0.5;
+        size.x *= 0.5;
+        size.y *= 0.5;
 
         self.pos = size * self.pos / self.screen_size;
         self.past_pos = size * self.past_pos / self.screen_size;
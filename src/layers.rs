@@ -1,5 +1,13 @@
 mod ligth;
+mod line;
+mod particle;
 mod quad;
+mod shape;
+mod stack;
 
 pub use ligth::*;
+pub use line::*;
+pub use particle::*;
 pub use quad::*;
+pub use shape::*;
+pub use stack::*;
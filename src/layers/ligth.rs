@@ -1,3 +1,6 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
 use crate::ligth_pipeline::*;
 use crate::math::*;
 use crate::shaders::*;
@@ -6,7 +9,10 @@ use crate::wgpu_components::*;
 pub struct LigthLayer {
     ligth_index: Uniform,
     ligths: Vec<CachedUniform<LigthUniform>>,
+    /// The last shadow is the instance of the ligth
     shadows: VecBuffer<ShadowInstance>,
+    free_ligths: BinaryHeap<Reverse<usize>>,
+    free_shadows: BinaryHeap<Reverse<usize>>,
 }
 
 impl LigthLayer {
@@ -17,6 +23,8 @@ impl LigthLayer {
             ligth_index: Uniform::new(ctx, wgpu::ShaderStages::VERTEX, &0u32),
             ligths: Vec::new(),
             shadows,
+            free_ligths: BinaryHeap::new(),
+            free_shadows: BinaryHeap::new(),
         }
     }
 
@@ -24,38 +32,56 @@ impl LigthLayer {
         self.shadows.get_mut(index)
     }
 
+    /// Returns the index of the shadow, reusing the removed ones
     pub fn add_shadow(&mut self, shadow: ShadowInstance) -> usize {
+        if let Some(Reverse(index)) = self.free_shadows.pop() {
+            *self.shadows.get_mut(index) = shadow;
+            return index;
+        }
+
         let index = self.shadows.len() - 1;
         *self.shadows.get_mut(index) = shadow;
         self.shadows.push(ShadowInstance::default());
         index
     }
 
+    /// The index can be reused by the next `add_shadow`
+    pub fn remove_shadow(&mut self, index: usize) {
+        *self.shadows.get_mut(index) = ShadowInstance::default();
+        self.free_shadows.push(Reverse(index));
+    }
+
     pub fn get_ligth_mut(&mut self, index: usize) -> &mut CachedUniform<LigthUniform> {
         &mut self.ligths[index]
     }
 
+    /// Returns the index of the ligth, reusing the removed ones
     pub fn add_ligth(&mut self, ctx: &WgpuContext, pos: Vec2, color: u32) -> usize {
-        let z_index = self
-            .ligths
-            .last()
-            .map(|ligth| ligth.data.z_index)
-            .unwrap_or(1.)
-            .next_down();
+        if let Some(Reverse(index)) = self.free_ligths.pop() {
+            let uniform = &mut self.ligths[index];
+            uniform.data = LigthUniform::new(pos, color);
+            uniform.needs_update = true;
+            return index;
+        }
 
         self.ligths.push(CachedUniform::new(
             ctx,
             wgpu::ShaderStages::VERTEX_FRAGMENT,
-            LigthUniform {
-                pos,
-                z_index,
-                color,
-            },
+            LigthUniform::new(pos, color),
         ));
         self.ligths.len() - 1
     }
 
-    pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>) {
+    /// The index can be reused by the next `add_ligth`
+    pub fn remove_ligth(&mut self, index: usize) {
+        self.ligths[index].data.color = 0;
+        self.free_ligths.push(Reverse(index));
+    }
+
+    /// Each ligth is drawn at a lower depth than the previous one, starting below `z_index`.
+    /// Ligths without color are skipped.
+    /// Returns the depth of the last ligth, so other ligth layers can be drawn after this one
+    pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, mut z_index: f32) -> f32 {
         let shadows_len = self.shadows.len() as u32;
 
         let idx = shadows_len - 1;
@@ -65,11 +91,31 @@ impl LigthLayer {
 
         pass.ligth.set_vertex_buffer(0, buffer);
         self.ligth_index.bind(2, &mut pass.ligth);
+        if let Some(unshadowed) = &mut pass.unshadowed {
+            unshadowed.set_vertex_buffer(0, buffer);
+            self.ligth_index.bind(2, unshadowed);
+        }
 
         for uniform in self.ligths.iter_mut() {
+            if uniform.data.color == 0 {
+                continue;
+            }
+
+            z_index = z_index.next_down();
+            if uniform.data.z_index != z_index {
+                uniform.data.z_index = z_index;
+                uniform.needs_update = true;
+            }
+
             uniform.update_buffers(pass.context);
             uniform.bind(3, &mut pass.ligth);
             pass.ligth.draw(0..4, 0..shadows_len);
+
+            if let Some(unshadowed) = &mut pass.unshadowed {
+                uniform.bind(3, unshadowed);
+                unshadowed.draw(0..4, idx..shadows_len);
+            }
         }
+        z_index
     }
 }
@@ -0,0 +1,386 @@
+use crate::ligth_pipeline::*;
+use crate::math::*;
+use crate::shaders::*;
+use crate::wgpu_components::*;
+use std::f32::consts::PI;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineCap {
+    /// Ends exactly at the first and last points
+    Butt,
+    /// Extended by half the width
+    Square,
+    Round,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct LineStyle {
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    /// Miters longer than `miter_limit` times the half width are beveled
+    pub miter_limit: f32,
+    /// Length of the dashes and the gaps between them, a zero gap draws a solid line
+    pub dash: Vec2,
+}
+
+impl LineStyle {
+    pub fn new(width: f32) -> Self {
+        Self {
+            width,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            miter_limit: 4.,
+            dash: Vec2::zero(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct LinePoint {
+    pub pos: Vec2,
+    /// 0xRRGGBBAA, interpolated along the segments
+    pub color: u32,
+}
+
+/// Polylines tessellated on the cpu
+pub struct LineLayer {
+    /// Triangles of each line, `None` for the removed ones
+    lines: Vec<Option<Vec<LineVertex>>>,
+    vertices: VecBuffer<LineVertex>,
+    needs_update: bool,
+}
+
+impl LineLayer {
+    pub fn new(ctx: &WgpuContext) -> Self {
+        Self {
+            lines: Vec::new(),
+            vertices: VecBuffer::new(ctx, wgpu::BufferUsages::VERTEX),
+            needs_update: false,
+        }
+    }
+
+    /// Returns the index of the line
+    pub fn add_polyline(&mut self, points: &[LinePoint], style: &LineStyle) -> usize {
+        self.add(tessellate(points, false, style))
+    }
+
+    /// Joins the last point with the first one
+    pub fn add_polygon(&mut self, points: &[LinePoint], style: &LineStyle) -> usize {
+        self.add(tessellate(points, true, style))
+    }
+
+    pub fn set_polyline(&mut self, index: usize, points: &[LinePoint], style: &LineStyle) {
+        self.lines[index] = Some(tessellate(points, false, style));
+        self.needs_update = true;
+    }
+
+    /// The index can be reused by the next add
+    pub fn remove(&mut self, index: usize) {
+        self.lines[index] = None;
+        self.needs_update = true;
+    }
+
+    fn add(&mut self, vertices: Vec<LineVertex>) -> usize {
+        self.needs_update = true;
+        if let Some(index) = self.lines.iter().position(Option::is_none) {
+            self.lines[index] = Some(vertices);
+            index
+        } else {
+            self.lines.push(Some(vertices));
+            self.lines.len() - 1
+        }
+    }
+
+    pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>) {
+        if self.needs_update {
+            self.vertices.clear();
+            for line in self.lines.iter().flatten() {
+                self.vertices.extend(line.iter().copied());
+            }
+            self.needs_update = false;
+        }
+
+        let len = self.vertices.len() as u32;
+        if let Some(vertices) = self.vertices.view(pass.context) {
+            pass.normal.set_vertex_buffer(0, vertices);
+            pass.normal.draw(0..len, 0..1);
+
+            pass.diffuse.set_vertex_buffer(0, vertices);
+            pass.diffuse.draw(0..len, 0..1);
+        }
+    }
+}
+
+/// Triangle list of the line
+fn tessellate(points: &[LinePoint], closed: bool, style: &LineStyle) -> Vec<LineVertex> {
+    let mut points: Vec<LinePoint> = points.to_vec();
+    points.dedup_by(|b, a| (b.pos - a.pos).length() < f32::EPSILON);
+    if closed
+        && points.len() > 2
+        && (points[0].pos - points[points.len() - 1].pos).length() < f32::EPSILON
+    {
+        points.pop();
+    }
+
+    let mut vertices = Vec::new();
+    if points.len() < 2 {
+        return vertices;
+    }
+
+    let half_width = style.width * 0.5;
+    let segments = if closed {
+        points.len()
+    } else {
+        points.len() - 1
+    };
+
+    let mut triangle = |a: (Vec2, u32, f32), b: (Vec2, u32, f32), c: (Vec2, u32, f32)| {
+        for (pos, color, distance) in [a, b, c] {
+            vertices.push(LineVertex {
+                pos,
+                color,
+                distance,
+                dash: style.dash,
+            });
+        }
+    };
+
+    // Direction of each segment and the distance at the start of each point
+    let mut directions = Vec::with_capacity(segments);
+    let mut distances = Vec::with_capacity(segments + 1);
+    distances.push(0.);
+    for i in 0..segments {
+        let delta = points[(i + 1) % points.len()].pos - points[i].pos;
+        directions.push(delta.normalize());
+        distances.push(distances[i] + delta.length());
+    }
+
+    for i in 0..segments {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let (da, db) = (distances[i], distances[i + 1]);
+        let normal = directions[i].perp() * half_width;
+
+        triangle(
+            (a.pos + normal, a.color, da),
+            (a.pos - normal, a.color, da),
+            (b.pos + normal, b.color, db),
+        );
+        triangle(
+            (b.pos + normal, b.color, db),
+            (a.pos - normal, a.color, da),
+            (b.pos - normal, b.color, db),
+        );
+    }
+
+    // Fills the outer side of the corners
+    let joins = if closed {
+        0..points.len()
+    } else {
+        1..points.len() - 1
+    };
+    for i in joins {
+        let point = points[i];
+        let distance = distances[i];
+        let vertex = |offset: Vec2| (point.pos + offset, point.color, distance);
+
+        let prev = directions[(i + segments - 1) % segments];
+        let next = directions[i % segments];
+
+        let turn = prev.cross(next);
+        if turn.abs() < 1e-6 && prev.dot(next) > 0. {
+            continue;
+        }
+
+        // The outer side is at the right when turning left
+        let side = if turn > 0. { -half_width } else { half_width };
+        let from = prev.perp() * side;
+        let to = next.perp() * side;
+
+        let miter_dir = (from + to).normalize();
+        let miter_length = half_width / miter_dir.dot(from / half_width).max(1e-6);
+
+        match style.join {
+            LineJoin::Miter if miter_length <= style.miter_limit * half_width => {
+                let miter = miter_dir * miter_length;
+                triangle(vertex(Vec2::zero()), vertex(from), vertex(miter));
+                triangle(vertex(Vec2::zero()), vertex(miter), vertex(to));
+            }
+            LineJoin::Round => {
+                let angle = f32::atan2(from.cross(to), from.dot(to));
+                fan(&mut triangle, vertex(Vec2::zero()), from, angle);
+            }
+            _ => triangle(vertex(Vec2::zero()), vertex(from), vertex(to)),
+        }
+    }
+
+    if !closed {
+        let last = points.len() - 1;
+        let caps = [
+            (points[0], distances[0], -directions[0]),
+            (points[last], distances[last], directions[last - 1]),
+        ];
+
+        for (point, distance, outwards) in caps {
+            let vertex = |offset: Vec2| (point.pos + offset, point.color, distance);
+            // Left side when looking outwards
+            let side = outwards.perp() * half_width;
+
+            match style.cap {
+                LineCap::Butt => {}
+                LineCap::Square => {
+                    let extension = outwards * half_width;
+                    triangle(vertex(side), vertex(-side), vertex(side + extension));
+                    triangle(
+                        vertex(side + extension),
+                        vertex(-side),
+                        vertex(-side + extension),
+                    );
+                }
+                LineCap::Round => {
+                    fan(&mut triangle, vertex(Vec2::zero()), -side, PI);
+                }
+            }
+        }
+    }
+
+    vertices
+}
+
+/// Triangles around `center` from the offset `from`, rotating `angle` radians
+fn fan(
+    triangle: &mut impl FnMut((Vec2, u32, f32), (Vec2, u32, f32), (Vec2, u32, f32)),
+    center: (Vec2, u32, f32),
+    from: Vec2,
+    angle: f32,
+) {
+    let steps = (angle.abs() / (PI / 8.)).ceil().max(1.) as usize;
+    let (pos, color, distance) = center;
+
+    for step in 0..steps {
+        let a = from.rotate(angle * step as f32 / steps as f32);
+        let b = from.rotate(angle * (step + 1) as f32 / steps as f32);
+        triangle(
+            center,
+            (pos + a, color, distance),
+            (pos + b, color, distance),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(positions: &[(f32, f32)]) -> Vec<LinePoint> {
+        positions
+            .iter()
+            .map(|(x, y)| LinePoint {
+                pos: Vec2::new(*x, *y),
+                color: 0xFFFFFFFF,
+            })
+            .collect()
+    }
+
+    fn style(join: LineJoin, cap: LineCap) -> LineStyle {
+        LineStyle {
+            join,
+            cap,
+            ..LineStyle::new(0.2)
+        }
+    }
+
+    fn triangles(positions: &[(f32, f32)], closed: bool, style: LineStyle) -> usize {
+        let vertices = tessellate(&points(positions), closed, &style);
+        assert_eq!(vertices.len() % 3, 0);
+        vertices.len() / 3
+    }
+
+    const SEGMENT: [(f32, f32); 2] = [(0., 0.), (1., 0.)];
+    const CORNER: [(f32, f32); 3] = [(0., 0.), (1., 0.), (1., 1.)];
+
+    #[test]
+    fn caps() {
+        assert_eq!(
+            triangles(&SEGMENT, false, style(LineJoin::Miter, LineCap::Butt)),
+            2
+        );
+        assert_eq!(
+            triangles(&SEGMENT, false, style(LineJoin::Miter, LineCap::Square)),
+            2 + 2 * 2
+        );
+        // Half turns in steps of an eighth of a turn
+        assert_eq!(
+            triangles(&SEGMENT, false, style(LineJoin::Miter, LineCap::Round)),
+            2 + 2 * 8
+        );
+
+        let vertices = tessellate(
+            &points(&SEGMENT),
+            false,
+            &style(LineJoin::Miter, LineCap::Square),
+        );
+        let max_x = vertices.iter().map(|v| v.pos.x).fold(f32::MIN, f32::max);
+        assert!((max_x - 1.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn joins() {
+        let butt = |join| triangles(&CORNER, false, style(join, LineCap::Butt));
+        assert_eq!(butt(LineJoin::Miter), 4 + 2);
+        assert_eq!(butt(LineJoin::Bevel), 4 + 1);
+        // A quarter turn
+        assert_eq!(butt(LineJoin::Round), 4 + 4);
+
+        // Too sharp for the miter limit
+        let sharp = [(0., 0.), (1., 0.), (0., 0.1)];
+        assert_eq!(
+            triangles(&sharp, false, style(LineJoin::Miter, LineCap::Butt)),
+            4 + 1
+        );
+
+        // Straight points need no join
+        let straight = [(0., 0.), (1., 0.), (2., 0.)];
+        assert_eq!(
+            triangles(&straight, false, style(LineJoin::Round, LineCap::Butt)),
+            4
+        );
+    }
+
+    #[test]
+    fn closed_polygons_join_every_corner_without_caps() {
+        let square = [(0., 0.), (1., 0.), (1., 1.), (0., 1.)];
+        assert_eq!(
+            triangles(&square, true, style(LineJoin::Miter, LineCap::Round)),
+            4 * 2 + 4 * 2
+        );
+        // The repeated first point is ignored
+        let repeated = [(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)];
+        assert_eq!(
+            triangles(&repeated, true, style(LineJoin::Miter, LineCap::Round)),
+            4 * 2 + 4 * 2
+        );
+    }
+
+    #[test]
+    fn degenerate_lines() {
+        let round = style(LineJoin::Miter, LineCap::Round);
+        assert_eq!(triangles(&[], false, round), 0);
+        assert_eq!(triangles(&[(1., 1.), (1., 1.)], false, round), 0);
+        assert_eq!(
+            triangles(
+                &[(0., 0.), (0., 0.), (1., 0.)],
+                false,
+                style(LineJoin::Miter, LineCap::Butt)
+            ),
+            2
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::ligth_pipeline::*;
+use crate::particles::*;
+use crate::shaders::*;
+use crate::wgpu_components::*;
+
+/// Emitters whose instances are rebuilt in bulk on each update
+pub struct ParticleLayer {
+    emitters: Vec<ParticleEmitter>,
+    quads: VecBuffer<QuadInstance>,
+    glows: VecBuffer<GlowInstance>,
+}
+
+impl ParticleLayer {
+    pub fn new(ctx: &WgpuContext) -> Self {
+        Self {
+            emitters: Vec::new(),
+            quads: VecBuffer::new(ctx, wgpu::BufferUsages::VERTEX),
+            glows: VecBuffer::new(ctx, wgpu::BufferUsages::VERTEX),
+        }
+    }
+
+    /// Returns the index of the emitter
+    pub fn add_emitter(&mut self, emitter: ParticleEmitter) -> usize {
+        self.emitters.push(emitter);
+        self.emitters.len() - 1
+    }
+
+    /// Advances all the emitters `delta_time` seconds
+    pub fn update(&mut self, delta_time: f32) {
+        self.quads.clear();
+        self.glows.clear();
+
+        for emitter in &mut self.emitters {
+            emitter.update(delta_time);
+
+            self.quads.extend(emitter.quads());
+            self.glows.extend(emitter.glows());
+        }
+    }
+
+    /// The glows are drawn in the ligth passes, which have to be binded with `GlowShader`
+    pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>) {
+        let len = self.glows.len() as u32;
+        if let Some(glows) = self.glows.view(pass.context) {
+            pass.ligth.set_vertex_buffer(0, glows);
+            pass.ligth.draw(0..4, 0..len);
+
+            if let Some(unshadowed) = &mut pass.unshadowed {
+                unshadowed.set_vertex_buffer(0, glows);
+                unshadowed.draw(0..4, 0..len);
+            }
+        }
+
+        let len = self.quads.len() as u32;
+        if let Some(quads) = self.quads.view(pass.context) {
+            pass.normal.set_vertex_buffer(0, quads);
+            pass.normal.draw(0..4, 0..len);
+
+            pass.diffuse.set_vertex_buffer(0, quads);
+            pass.diffuse.draw(0..4, 0..len);
+        }
+    }
+}
@@ -1,21 +1,204 @@
+mod grid;
+
+pub use grid::*;
+
+use crate::camera::*;
 use crate::ligth_pipeline::*;
 use crate::shaders::*;
 use crate::wgpu_components::*;
 
 pub struct QuadLayer {
-    pub buffer: VecBuffer<QuadInstance>,
+    pub buffer: SparseBuffer<QuadInstance>,
+    pub order: QuadOrder,
+    /// Enabled by `enable_culling`
+    grid: Option<QuadGrid>,
+    /// Area of the world that is drawn, everything when `None`
+    view: Option<CameraBounds>,
+    /// Compacted quads that overlap the view, in draw order
+    visible: VecBuffer<QuadInstance>,
+    visible_indices: Vec<usize>,
+    /// Position of each slot in `visible`
+    visible_pos: Vec<Option<usize>>,
+    /// Sort key of each slot when `visible` was built
+    keys: Vec<(f32, f32)>,
+    /// Set by `set_z`, with the generation of the slot it was set for
+    z: Vec<(u32, f32)>,
+    /// State that `visible` was built with, `None` when it has to be rebuilt
+    built: Option<BuiltState>,
+    /// Slots of the lit quads without `QuadInstance::RECEIVES_SHADOWS`
+    unshadowed: Vec<bool>,
+    unshadowed_len: usize,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+struct BuiltState {
+    order: QuadOrder,
+    view: Option<CameraBounds>,
+    slots: usize,
+}
+
+/// Draw order of the quads of a layer
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QuadOrder {
+    /// Order of the slots in the buffer
+    Slots,
+    /// Lower z first, see `QuadLayer::set_z`. Ties in slot order
+    Z,
+    /// Lower z first, then from top to bottom by the pivot position.
+    /// For top-down scenes where lower quads are in front
+    YSort,
+}
+
+impl QuadOrder {
+    /// Quads are drawn by increasing key, the y is compared in reverse
+    fn key(self, z: f32, quad: &QuadInstance) -> (f32, f32) {
+        match self {
+            QuadOrder::Slots => (0., 0.),
+            QuadOrder::Z => (z, 0.),
+            QuadOrder::YSort => (z, quad.pos.y),
+        }
+    }
+}
+
+/// Stable sort of the indices by their `QuadOrder::key`, ties keep their order
+fn sort_by_keys(indices: &mut [usize], keys: &[(f32, f32)]) {
+    indices.sort_by(|a, b| {
+        let (a, b) = (keys[*a], keys[*b]);
+        a.0.total_cmp(&b.0).then(b.1.total_cmp(&a.1))
+    });
 }
 
 impl QuadLayer {
     pub fn new(ctx: &WgpuContext) -> Self {
         Self {
-            buffer: VecBuffer::new(ctx, wgpu::BufferUsages::VERTEX),
+            buffer: SparseBuffer::new(ctx, wgpu::BufferUsages::VERTEX),
+            order: QuadOrder::Slots,
+            grid: None,
+            view: None,
+            visible: VecBuffer::new(ctx, wgpu::BufferUsages::VERTEX),
+            visible_indices: Vec::new(),
+            visible_pos: Vec::new(),
+            keys: Vec::new(),
+            z: Vec::new(),
+            built: None,
+            unshadowed: Vec::new(),
+            unshadowed_len: 0,
+        }
+    }
+
+    /// Only the quads that overlap the view are uploaded and drawn, see `set_view`.
+    /// `cell_size` should be around the size of the common quads
+    pub fn enable_culling(&mut self, cell_size: f32) {
+        let mut grid = QuadGrid::new(cell_size);
+        for index in 0..self.buffer.len() {
+            grid.update(index, self.buffer.get_ref(index));
+        }
+        self.grid = Some(grid);
+        self.built = None;
+    }
+
+    pub fn set_view(&mut self, view: Option<CameraBounds>) {
+        self.view = view;
+    }
+
+    /// Sort key of the quad for `QuadOrder::Z` and `QuadOrder::YSort`, 0 by default.
+    /// Reset when the slot is removed
+    pub fn set_z(&mut self, index: usize, z: f32) {
+        if self.z.len() <= index {
+            self.z.resize(index + 1, (0, 0.));
+        }
+        let z = (self.buffer.generation(index), z);
+        if self.z[index] != z {
+            self.z[index] = z;
+            self.built = None;
+        }
+    }
+
+    fn z(&self, index: usize) -> f32 {
+        match self.z.get(index) {
+            Some(&(generation, z)) if generation == self.buffer.generation(index) => z,
+            _ => 0.,
         }
     }
 
+    /// Applies the changed quads, and returns whether some quad needs the unshadowed
+    /// ligth pass when the layer is `lit`. Called every frame, even when the layer is hidden
+    pub fn prepare(&mut self, lit: bool) -> bool {
+        if lit || self.grid.is_some() || self.order != QuadOrder::Slots {
+            self.buffer.track_changes();
+        }
+
+        let changes = self.buffer.take_changes();
+        let slots = self.buffer.len();
+        if self.keys.len() < slots {
+            self.keys.resize(slots, (0., 0.));
+            self.visible_pos.resize(slots, None);
+            self.unshadowed.resize(slots, false);
+        }
+
+        for &index in &changes {
+            self.update_unshadowed(index);
+
+            let quad = self.buffer.get_ref(index);
+
+            if let Some(grid) = &mut self.grid {
+                if grid.update(index, quad) {
+                    self.built = None;
+                }
+            }
+
+            if self.built.is_some() {
+                let key = self.order.key(self.z(index), quad);
+                if self.keys[index] != key {
+                    self.keys[index] = key;
+                    self.built = None;
+                }
+            }
+        }
+
+        // The quads that stay visible are copied instead of rebuilding them
+        if self.built.is_some() {
+            for &index in &changes {
+                if let Some(pos) = self.visible_pos[index] {
+                    *self.visible.get_mut(pos) = *self.buffer.get_ref(index);
+                }
+            }
+        }
+
+        self.unshadowed_len > 0
+    }
+
+    fn update_unshadowed(&mut self, index: usize) {
+        let quad = self.buffer.get_ref(index);
+        let flags = QuadInstance::RECEIVES_LIGTH | QuadInstance::RECEIVES_SHADOWS;
+        let unshadowed = quad.flags & flags == QuadInstance::RECEIVES_LIGTH
+            && quad.size.x != 0.
+            && quad.size.y != 0.;
+
+        if unshadowed != self.unshadowed[index] {
+            self.unshadowed[index] = unshadowed;
+            if unshadowed {
+                self.unshadowed_len += 1;
+            } else {
+                self.unshadowed_len -= 1;
+            }
+        }
+    }
+
+    /// `prepare` has to be called before, in the same frame
     pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>) {
-        let len = self.buffer.len() as u32;
-        if let Some(quads) = self.buffer.view(pass.context) {
+        let culled = self.grid.is_some() && self.view.is_some();
+        let (quads, len) = if culled || self.order != QuadOrder::Slots {
+            self.update_visible();
+            (self.visible.view(pass.context), self.visible_indices.len())
+        } else {
+            self.built = None;
+            let len = self.buffer.len();
+            (self.buffer.view(pass.context), len)
+        };
+
+        if let Some(quads) = quads {
+            let len = len as u32;
             pass.normal.set_vertex_buffer(0, quads);
             pass.normal.draw(0..4, 0..len);
 
@@ -23,4 +206,91 @@ impl QuadLayer {
             pass.diffuse.draw(0..4, 0..len);
         }
     }
+
+    /// Rebuilds the compacted quads when the culled or sorted indices change
+    fn update_visible(&mut self) {
+        let state = BuiltState {
+            order: self.order,
+            view: self.view.filter(|_| self.grid.is_some()),
+            slots: self.buffer.len(),
+        };
+        let rebuild = match (self.built, self.grid.as_ref()) {
+            (Some(built), _) if built.order != state.order || built.slots != state.slots => true,
+            (Some(built), Some(grid)) => match (built.view, state.view) {
+                (Some(a), Some(b)) => !grid.same_cells(a, b),
+                (a, b) => a.is_some() != b.is_some(),
+            },
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        if !rebuild {
+            return;
+        }
+
+        if self.built.is_none_or(|built| built.order != state.order) {
+            // The keys of the unchanged slots are from another order
+            for index in 0..state.slots {
+                self.keys[index] = self.order.key(self.z(index), self.buffer.get_ref(index));
+            }
+        }
+        self.built = Some(state);
+
+        self.visible_indices.clear();
+        match (&mut self.grid, state.view) {
+            (Some(grid), Some(view)) => {
+                grid.query(view, &mut self.visible_indices);
+                self.visible_indices.sort_unstable();
+            }
+            _ => self.visible_indices.extend(0..state.slots),
+        }
+        sort_by_keys(&mut self.visible_indices, &self.keys);
+
+        self.visible_pos.fill(None);
+        for (pos, index) in self.visible_indices.iter().enumerate() {
+            self.visible_pos[*index] = Some(pos);
+        }
+
+        self.visible.clear();
+        self.visible.extend(
+            self.visible_indices
+                .iter()
+                .map(|index| *self.buffer.get_ref(*index)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::*;
+
+    fn sorted(order: QuadOrder, quads: &[(f32, f32)]) -> Vec<usize> {
+        let keys: Vec<_> = quads
+            .iter()
+            .map(|(z, y)| {
+                let quad = QuadInstance::new_color(Vec2::new(0., *y), Vec2::new(1., 1.), 0);
+                order.key(*z, &quad)
+            })
+            .collect();
+        let mut indices: Vec<usize> = (0..quads.len()).collect();
+        sort_by_keys(&mut indices, &keys);
+        indices
+    }
+
+    const QUADS: [(f32, f32); 5] = [(0., 0.), (1., 2.), (0., 1.), (-1., -1.), (0., 1.)];
+
+    #[test]
+    fn slots_keep_their_order() {
+        assert_eq!(sorted(QuadOrder::Slots, &QUADS), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn z_ties_keep_the_slot_order() {
+        assert_eq!(sorted(QuadOrder::Z, &QUADS), [3, 0, 2, 4, 1]);
+    }
+
+    #[test]
+    fn y_sort_draws_the_lower_quads_in_front() {
+        assert_eq!(sorted(QuadOrder::YSort, &QUADS), [3, 2, 4, 0, 1]);
+    }
 }
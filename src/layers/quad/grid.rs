@@ -0,0 +1,254 @@
+use crate::camera::*;
+use crate::math::*;
+use crate::shaders::*;
+use std::collections::HashMap;
+
+/// Uniform grid over the bounds of the quads of a layer
+pub struct QuadGrid {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    /// Cells covered by each quad, `None` for the disabled ones
+    covered: Vec<Option<CellRange>>,
+    /// Quads covering too many cells, tested on every query
+    large: Vec<usize>,
+    /// Last query that found each quad, to skip the duplicates
+    found: Vec<u32>,
+    query: u32,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct CellRange {
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+impl CellRange {
+    const MAX_CELLS: i64 = 64;
+
+    fn cells(self) -> impl Iterator<Item = (i32, i32)> {
+        (self.min.0..=self.max.0).flat_map(move |x| (self.min.1..=self.max.1).map(move |y| (x, y)))
+    }
+
+    fn len(self) -> i64 {
+        // Far apart cells overflow an i32, and the whole grid an i64
+        let width = self.max.0 as i64 - self.min.0 as i64 + 1;
+        let height = self.max.1 as i64 - self.min.1 as i64 + 1;
+        width.saturating_mul(height)
+    }
+}
+
+impl QuadGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            covered: Vec::new(),
+            large: Vec::new(),
+            found: Vec::new(),
+            query: 0,
+        }
+    }
+
+    /// Moves the quad to the cells of its new bounds, returns false when the cells are the same
+    pub fn update(&mut self, index: usize, quad: &QuadInstance) -> bool {
+        if self.covered.len() <= index {
+            self.covered.resize(index + 1, None);
+            self.found.resize(index + 1, 0);
+        }
+
+        let range = quad_bounds(quad).map(|bounds| self.cell_range(bounds));
+        if range == self.covered[index] {
+            return false;
+        }
+
+        if let Some(old) = self.covered[index] {
+            if old.len() > CellRange::MAX_CELLS {
+                self.large.retain(|quad| *quad != index);
+            } else {
+                for cell in old.cells() {
+                    let quads = self.cells.get_mut(&cell).unwrap();
+                    quads.swap_remove(quads.iter().position(|quad| *quad == index).unwrap());
+                }
+            }
+        }
+
+        if let Some(new) = range {
+            if new.len() > CellRange::MAX_CELLS {
+                self.large.push(index);
+            } else {
+                for cell in new.cells() {
+                    self.cells.entry(cell).or_default().push(index);
+                }
+            }
+        }
+        self.covered[index] = range;
+        true
+    }
+
+    /// Whether both bounds query the same cells
+    pub fn same_cells(&self, a: CameraBounds, b: CameraBounds) -> bool {
+        self.cell_range(a) == self.cell_range(b)
+    }
+
+    /// Appends the quads that may overlap `bounds`, unsorted
+    pub fn query(&mut self, bounds: CameraBounds, out: &mut Vec<usize>) {
+        self.query = self.query.wrapping_add(1);
+        let range = self.cell_range(bounds);
+
+        let candidates = self.large.iter();
+        if range.len() > self.cells.len() as i64 {
+            // Cheaper to walk the occupied cells
+            let cells = self.cells.iter().filter(|(cell, _)| {
+                (range.min.0..=range.max.0).contains(&cell.0)
+                    && (range.min.1..=range.max.1).contains(&cell.1)
+            });
+            for quad in candidates.chain(cells.flat_map(|(_, quads)| quads)) {
+                if self.found[*quad] != self.query {
+                    self.found[*quad] = self.query;
+                    out.push(*quad);
+                }
+            }
+        } else {
+            let cells = range.cells().filter_map(|cell| self.cells.get(&cell));
+            for quad in candidates.chain(cells.flatten()) {
+                if self.found[*quad] != self.query {
+                    self.found[*quad] = self.query;
+                    out.push(*quad);
+                }
+            }
+        }
+    }
+
+    fn cell_range(&self, bounds: CameraBounds) -> CellRange {
+        let cell = |pos: Vec2| {
+            (
+                (pos.x / self.cell_size).floor() as i32,
+                (pos.y / self.cell_size).floor() as i32,
+            )
+        };
+        CellRange {
+            min: cell(bounds.min),
+            max: cell(bounds.max),
+        }
+    }
+}
+
+/// Box around the rotated quad, `None` when disabled
+fn quad_bounds(quad: &QuadInstance) -> Option<CameraBounds> {
+    if quad.size.x == 0. || quad.size.y == 0. {
+        return None;
+    }
+
+    // Same placement as the vertex shader, the flip mirrors around the pivot
+    let min = (Vec2::new(-0.5, -0.5) - quad.pivot) * quad.size;
+    let max = (Vec2::new(0.5, 0.5) - quad.pivot) * quad.size;
+    let flip = Vec2::new(
+        if quad.flags & QuadInstance::FLIP_X != 0 {
+            -1.
+        } else {
+            1.
+        },
+        if quad.flags & QuadInstance::FLIP_Y != 0 {
+            -1.
+        } else {
+            1.
+        },
+    );
+
+    let mut bounds = CameraBounds {
+        min: Vec2::new(f32::MAX, f32::MAX),
+        max: Vec2::new(f32::MIN, f32::MIN),
+    };
+    for corner in [min, max, Vec2::new(min.x, max.y), Vec2::new(max.x, min.y)] {
+        // The angle of the quads is clockwise
+        let pos = (corner * flip).rotate(-quad.angle) + quad.pos;
+        bounds.min = Vec2::new(bounds.min.x.min(pos.x), bounds.min.y.min(pos.y));
+        bounds.max = Vec2::new(bounds.max.x.max(pos.x), bounds.max.y.max(pos.y));
+    }
+    Some(bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> QuadInstance {
+        QuadInstance::new_color(Vec2::new(x, y), Vec2::new(size, size), 0xFFFFFFFF)
+    }
+
+    fn bounds(min: (f32, f32), max: (f32, f32)) -> CameraBounds {
+        CameraBounds {
+            min: Vec2::new(min.0, min.1),
+            max: Vec2::new(max.0, max.1),
+        }
+    }
+
+    fn query(grid: &mut QuadGrid, view: CameraBounds) -> Vec<usize> {
+        let mut found = Vec::new();
+        grid.query(view, &mut found);
+        found.sort_unstable();
+        found
+    }
+
+    #[test]
+    fn finds_the_quads_in_the_view() {
+        let mut grid = QuadGrid::new(1.);
+        assert!(grid.update(0, &square(0.5, 0.5, 0.5)));
+        assert!(grid.update(1, &square(5.5, 0.5, 0.5)));
+        // Over four cells, found once
+        assert!(grid.update(2, &square(2., 2., 0.5)));
+
+        assert_eq!(query(&mut grid, bounds((0., 0.), (2.5, 2.5))), [0, 2]);
+        assert_eq!(
+            query(&mut grid, bounds((-100., -100.), (100., 100.))),
+            [0, 1, 2]
+        );
+        assert_eq!(query(&mut grid, bounds((10., 10.), (11., 11.))), []);
+    }
+
+    #[test]
+    fn moves_and_disables_quads() {
+        let mut grid = QuadGrid::new(1.);
+        grid.update(0, &square(0.5, 0.5, 0.5));
+        // Same cell
+        assert!(!grid.update(0, &square(0.6, 0.4, 0.5)));
+
+        assert!(grid.update(0, &square(5.5, 0.5, 0.5)));
+        assert_eq!(query(&mut grid, bounds((0., 0.), (1., 1.))), []);
+        assert_eq!(query(&mut grid, bounds((5., 0.), (6., 1.))), [0]);
+
+        assert!(grid.update(0, &square(5.5, 0.5, 0.)));
+        assert_eq!(query(&mut grid, bounds((5., 0.), (6., 1.))), []);
+    }
+
+    #[test]
+    fn large_quads_are_always_candidates() {
+        let mut grid = QuadGrid::new(1.);
+        grid.update(0, &square(0., 0., 100.));
+        assert_eq!(grid.large, [0]);
+        assert!(grid.cells.values().all(Vec::is_empty));
+        assert_eq!(query(&mut grid, bounds((30., 30.), (31., 31.))), [0]);
+
+        grid.update(0, &square(0., 0., 0.5));
+        assert!(grid.large.is_empty());
+        assert_eq!(query(&mut grid, bounds((0., 0.), (1., 1.))), [0]);
+    }
+
+    #[test]
+    fn far_apart_cells_dont_overflow() {
+        let range = CellRange {
+            min: (i32::MIN, i32::MIN),
+            max: (i32::MAX, i32::MAX),
+        };
+        assert_eq!(range.len(), i64::MAX);
+        let range = CellRange {
+            min: (i32::MIN, 0),
+            max: (i32::MAX, 1),
+        };
+        assert_eq!(range.len(), 1 << 33);
+
+        let mut grid = QuadGrid::new(1.);
+        grid.update(0, &square(0., 0., 1e12));
+        assert_eq!(grid.large, [0]);
+    }
+}
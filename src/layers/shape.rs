@@ -0,0 +1,26 @@
+use crate::ligth_pipeline::*;
+use crate::shaders::*;
+use crate::wgpu_components::*;
+
+pub struct ShapeLayer {
+    pub buffer: SparseBuffer<ShapeInstance>,
+}
+
+impl ShapeLayer {
+    pub fn new(ctx: &WgpuContext) -> Self {
+        Self {
+            buffer: SparseBuffer::new(ctx, wgpu::BufferUsages::VERTEX),
+        }
+    }
+
+    pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>) {
+        let len = self.buffer.len() as u32;
+        if let Some(shapes) = self.buffer.view(pass.context) {
+            pass.normal.set_vertex_buffer(0, shapes);
+            pass.normal.draw(0..4, 0..len);
+
+            pass.diffuse.set_vertex_buffer(0, shapes);
+            pass.diffuse.draw(0..4, 0..len);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use super::*;
+use crate::camera::*;
+use crate::ligth_pipeline::*;
+use crate::math::*;
+use crate::shaders::*;
+
+/// Layer of a `LayerStack`, stays valid when other layers are inserted
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LayerId(usize);
+
+pub enum LayerContent {
+    /// Only drawn in the ligth pass, in the order of the stack
+    Ligths(LigthLayer),
+    Quads(Box<QuadLayer>),
+    Shapes(ShapeLayer),
+    Lines(LineLayer),
+    Particles(ParticleLayer),
+}
+
+/// Camera that a layer is drawn with
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LayerCamera {
+    /// Index in `LayerStack::cameras`
+    pub camera: usize,
+    /// See `Camera::add_parallax`
+    pub parallax: Option<usize>,
+}
+
+pub struct Layer {
+    pub name: &'static str,
+    pub content: LayerContent,
+    /// Ignored by ligths layers
+    pub lighting: Lighting,
+    pub camera: LayerCamera,
+    pub blend: BlendMode,
+    pub visible: bool,
+}
+
+/// Named layers drawn from bottom to top
+pub struct LayerStack {
+    /// In draw order
+    layers: Vec<Layer>,
+    /// Index in `layers` of each `LayerId`
+    positions: Vec<usize>,
+    pub cameras: Vec<Camera>,
+}
+
+impl LayerCamera {
+    pub fn new(camera: usize) -> Self {
+        Self {
+            camera,
+            parallax: None,
+        }
+    }
+}
+
+impl Layer {
+    /// Lit, alpha blended and visible, with the first camera
+    pub fn new(name: &'static str, content: LayerContent) -> Self {
+        Self {
+            name,
+            content,
+            lighting: Lighting::Lit,
+            camera: LayerCamera::new(0),
+            blend: BlendMode::Alpha,
+            visible: true,
+        }
+    }
+
+    pub fn unlit(mut self) -> Self {
+        self.lighting = Lighting::Unlit;
+        self
+    }
+
+    pub fn with_camera(mut self, camera: LayerCamera) -> Self {
+        self.camera = camera;
+        self
+    }
+
+    pub fn with_blend(mut self, blend: BlendMode) -> Self {
+        self.blend = blend;
+        self
+    }
+}
+
+impl LayerStack {
+    pub fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            positions: Vec::new(),
+            cameras: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the camera, for `LayerCamera`
+    pub fn add_camera(&mut self, camera: Camera) -> usize {
+        self.cameras.push(camera);
+        self.cameras.len() - 1
+    }
+
+    /// Adds the layer on top of the others
+    pub fn push(&mut self, layer: Layer) -> LayerId {
+        self.insert(self.layers.len(), layer)
+    }
+
+    /// Adds the layer just below `other`
+    pub fn insert_below(&mut self, other: LayerId, layer: Layer) -> LayerId {
+        self.insert(self.positions[other.0], layer)
+    }
+
+    /// Adds the layer just above `other`
+    pub fn insert_above(&mut self, other: LayerId, layer: Layer) -> LayerId {
+        self.insert(self.positions[other.0] + 1, layer)
+    }
+
+    fn insert(&mut self, position: usize, layer: Layer) -> LayerId {
+        assert!(
+            self.find(layer.name).is_none(),
+            "Layer {:?} already exists",
+            layer.name
+        );
+        for other in &mut self.positions {
+            if *other >= position {
+                *other += 1;
+            }
+        }
+        self.layers.insert(position, layer);
+        self.positions.push(position);
+        LayerId(self.positions.len() - 1)
+    }
+
+    pub fn find(&self, name: &str) -> Option<LayerId> {
+        let position = self.layers.iter().position(|layer| layer.name == name)?;
+        let id = self.positions.iter().position(|other| *other == position)?;
+        Some(LayerId(id))
+    }
+
+    pub fn layer_mut(&mut self, id: LayerId) -> &mut Layer {
+        &mut self.layers[self.positions[id.0]]
+    }
+
+    pub fn ligths(&mut self, id: LayerId) -> Option<&mut LigthLayer> {
+        match &mut self.layer_mut(id).content {
+            LayerContent::Ligths(layer) => Some(layer),
+            _ => None,
+        }
+    }
+
+    pub fn quads(&mut self, id: LayerId) -> Option<&mut QuadLayer> {
+        match &mut self.layer_mut(id).content {
+            LayerContent::Quads(layer) => Some(layer),
+            _ => None,
+        }
+    }
+
+    pub fn shapes(&mut self, id: LayerId) -> Option<&mut ShapeLayer> {
+        match &mut self.layer_mut(id).content {
+            LayerContent::Shapes(layer) => Some(layer),
+            _ => None,
+        }
+    }
+
+    pub fn lines(&mut self, id: LayerId) -> Option<&mut LineLayer> {
+        match &mut self.layer_mut(id).content {
+            LayerContent::Lines(layer) => Some(layer),
+            _ => None,
+        }
+    }
+
+    pub fn particles(&mut self, id: LayerId) -> Option<&mut ParticleLayer> {
+        match &mut self.layer_mut(id).content {
+            LayerContent::Particles(layer) => Some(layer),
+            _ => None,
+        }
+    }
+
+    /// To transform the input events of a quads layer with its camera
+    pub fn camera_and_quads(
+        &mut self,
+        camera: usize,
+        id: LayerId,
+    ) -> Option<(&Camera, &mut QuadLayer)> {
+        let LayerContent::Quads(quads) = &mut self.layers[self.positions[id.0]].content else {
+            return None;
+        };
+        Some((&self.cameras[camera], quads))
+    }
+
+    pub fn resize(&mut self, size: Vec2) {
+        for camera in &mut self.cameras {
+            camera.resize(size);
+        }
+    }
+
+    /// Returns whether a visible lit layer needs the unshadowed ligth pass,
+    /// see `LigthFrame::create_render_pass`
+    pub fn prepare(&mut self) -> bool {
+        let mut unshadowed = false;
+        for layer in &mut self.layers {
+            if let LayerContent::Quads(quads) = &mut layer.content {
+                // Every layer applies its changes, even the ones not drawn
+                let lit = layer.lighting == Lighting::Lit;
+                unshadowed |= quads.prepare(lit) && lit && layer.visible;
+            }
+        }
+        unshadowed
+    }
+
+    /// Advances the cameras and the particles `delta_time` seconds
+    pub fn update(&mut self, delta_time: f32) {
+        for camera in &mut self.cameras {
+            camera.update(delta_time);
+        }
+
+        for layer in &mut self.layers {
+            if let LayerContent::Particles(particles) = &mut layer.content {
+                particles.update(delta_time);
+            }
+        }
+    }
+
+    /// `prepare` has to be called before creating the pass
+    pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, shaders: &'a Shaders) {
+        for camera in &mut self.cameras {
+            camera.update_buffers(pass.context);
+        }
+
+        // Ligths of upper layers are drawn below the previous ones
+        let mut z_index = 1.;
+
+        for layer in &mut self.layers {
+            let Layer {
+                content,
+                lighting,
+                camera,
+                blend,
+                visible,
+                ..
+            } = layer;
+            if !*visible {
+                continue;
+            }
+
+            let (camera, parallax) = (&self.cameras[camera.camera], camera.parallax);
+            match parallax {
+                Some(parallax) => camera.bind_parallax(parallax, pass),
+                None => camera.bind(pass),
+            }
+
+            match content {
+                LayerContent::Ligths(ligths) => {
+                    shaders.ligth.bind(pass);
+                    z_index = ligths.draw(pass, z_index);
+                }
+                LayerContent::Quads(quads) => {
+                    quads.set_view(Some(match parallax {
+                        Some(parallax) => camera.parallax_view_bounds(parallax),
+                        None => camera.view_bounds(),
+                    }));
+                    shaders.quad.bind(pass, *lighting, *blend);
+                    quads.draw(pass);
+                }
+                LayerContent::Shapes(shapes) => {
+                    shaders.shape.bind(pass, *lighting, *blend);
+                    shapes.draw(pass);
+                }
+                LayerContent::Lines(lines) => {
+                    shaders.line.bind(pass, *lighting, *blend);
+                    lines.draw(pass);
+                }
+                LayerContent::Particles(particles) => {
+                    // The glows are added after the shadowed ligths below them
+                    shaders.glow.bind(pass);
+                    shaders.quad.bind(pass, *lighting, *blend);
+                    particles.draw(pass);
+                }
+            }
+        }
+    }
+}
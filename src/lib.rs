@@ -1,5 +1,4 @@
-#![feature(const_for, float_next_up_down)]
-
+mod animation;
 mod camera;
 mod error;
 mod font;
@@ -8,15 +7,19 @@ mod layers;
 mod ligth_pipeline;
 mod math;
 mod objects;
+mod particles;
+mod scene_graph;
 mod scene_manager;
 mod scenes;
 mod shaders;
 mod shapes;
+mod sprite;
 mod texture_atlas;
 mod wgpu_components;
 
 use error::ErrResult;
 use scene_manager::SceneManager;
+use scenes::{Demo, Lobby, Scene};
 use smaa::{SmaaMode, SmaaTarget};
 use wgpu_components::WgpuContext;
 use winit::{
@@ -26,7 +29,7 @@ use winit::{
     window::{Window, WindowBuilder},
 };
 
-struct State {
+struct State<S> {
     surface: wgpu::Surface,
     context: WgpuContext,
     config: wgpu::SurfaceConfiguration,
@@ -34,10 +37,10 @@ struct State {
     window: Window,
     smaa_target: SmaaTarget,
 
-    scene_manager: SceneManager,
+    scene_manager: SceneManager<S>,
 }
 
-impl State {
+impl<S: Scene> State<S> {
     // Creating some of the wgpu types requires async code
     async fn new(window: Window) -> ErrResult<Self> {
         let size = window.inner_size();
@@ -172,7 +175,17 @@ impl State {
     }
 }
 
+/// Runs the game
 pub async fn run() {
+    run_scene::<Lobby>().await;
+}
+
+/// Runs a showcase of the renderer features
+pub async fn run_demo() {
+    run_scene::<Demo>().await;
+}
+
+async fn run_scene<S: Scene + 'static>() {
     env_logger::init();
 
     let event_loop = EventLoop::new();
@@ -186,7 +199,7 @@ pub async fn run() {
         .build(&event_loop)
         .unwrap();
 
-    let mut state = State::new(window).await.unwrap();
+    let mut state = State::<S>::new(window).await.unwrap();
 
     event_loop.run(move |event, _, control_flow| match event {
         Event::RedrawRequested(window_id) if window_id == state.window().id() => {
@@ -3,6 +3,8 @@ use crate::wgpu_components::*;
 pub struct LigthTextures {
     pub normal: wgpu::TextureView,
     pub ligth: wgpu::TextureView,
+    /// Same ligths without the shadows, for the quads that don't receive them
+    pub unshadowed: wgpu::TextureView,
     pub ligth_depth: wgpu::TextureView,
 }
 
@@ -13,6 +15,7 @@ pub struct LigthPipeline {
 struct Encoders {
     normal: wgpu::CommandEncoder,
     ligth: wgpu::CommandEncoder,
+    unshadowed: wgpu::CommandEncoder,
     diffuse: wgpu::CommandEncoder,
 }
 
@@ -27,13 +30,16 @@ pub struct LigthFrame<'a> {
 pub struct LigthRenderPass<'a> {
     pub normal: wgpu::RenderPass<'a>,
     pub ligth: wgpu::RenderPass<'a>,
+    /// Only when some quad doesn't receive shadows, see `create_render_pass`
+    pub unshadowed: Option<wgpu::RenderPass<'a>>,
     pub diffuse: wgpu::RenderPass<'a>,
 
     pub context: &'a WgpuContext,
 }
 
 impl<'a> LigthFrame<'a> {
-    pub fn create_render_pass<'b>(&'b mut self) -> LigthRenderPass<'b> {
+    /// The unshadowed pass is needed to draw quads without `QuadInstance::RECEIVES_SHADOWS`
+    pub fn create_render_pass<'b>(&'b mut self, unshadowed: bool) -> LigthRenderPass<'b> {
         LigthRenderPass {
             context: self.context,
             normal: self
@@ -78,6 +84,22 @@ impl<'a> LigthFrame<'a> {
                         stencil_ops: None,
                     }),
                 }),
+            unshadowed: unshadowed.then(|| {
+                self.encoders
+                    .unshadowed
+                    .begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: Some("Unshadowed ligth render pass"),
+                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                            view: &self.ligth_pipeline.textures.unshadowed,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                                store: true,
+                            },
+                        })],
+                        depth_stencil_attachment: None,
+                    })
+            }),
             diffuse: self
                 .encoders
                 .diffuse
@@ -114,6 +136,11 @@ impl Encoders {
                 .create_command_encoder(&wgpu::CommandEncoderDescriptor {
                     label: Some("Ligth command encoder"),
                 }),
+            unshadowed: ctx
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("Unshadowed ligth command encoder"),
+                }),
             diffuse: ctx
                 .device
                 .create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -126,6 +153,7 @@ impl Encoders {
         queue.submit([
             self.normal.finish(),
             self.ligth.finish(),
+            self.unshadowed.finish(),
             self.diffuse.finish(),
         ]);
     }
@@ -162,6 +190,12 @@ impl LigthTextures {
                     ..Default::default()
                 },
             ),
+            unshadowed: ctx.device.create_texture(&texture_desc).create_view(
+                &wgpu::TextureViewDescriptor {
+                    label: Some("Unshadowed ligth texture"),
+                    ..Default::default()
+                },
+            ),
             ligth_depth: ctx
                 .device
                 .create_texture(&wgpu::TextureDescriptor {
@@ -14,6 +14,40 @@ impl Vec2 {
     pub const fn zero() -> Self {
         Self { x: 0., y: 0. }
     }
+    #[inline]
+    pub fn dot(self, rhs: Vec2) -> f32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+    /// Z component of the 3D cross product
+    #[inline]
+    pub fn cross(self, rhs: Vec2) -> f32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+    /// Returns zero for a zero vector
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let length = self.length();
+        if length > 0. {
+            self / length
+        } else {
+            self
+        }
+    }
+    /// Rotated 90 degrees counterclockwise
+    #[inline]
+    pub fn perp(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+    /// Rotated counterclockwise by `angle` radians
+    #[inline]
+    pub fn rotate(self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
 }
 
 impl std::ops::Neg for Vec2 {
@@ -81,3 +115,35 @@ impl_op_assign!(AddAssign, add_assign, +=);
 impl_op_assign!(SubAssign, sub_assign, -=);
 impl_op_assign!(MulAssign, mul_assign, *=);
 impl_op_assign!(DivAssign, div_assign, /=);
+
+/// Small and fast pseudo random numbers (xorshift), not for anything secure
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u32,
+}
+
+impl Rng {
+    pub fn new(seed: u32) -> Self {
+        // Zero is a fixed point of xorshift
+        Self { state: seed.max(1) }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Uniform in `[0, 1)`
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1 << 24) as f32
+    }
+
+    /// Uniform in `[range.start, range.end)`
+    pub fn range(&mut self, range: std::ops::Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * self.next_f32()
+    }
+}
@@ -1,8 +1,10 @@
 mod block;
+mod nine_slice;
 mod text_button;
 mod text_input;
 
 pub use block::*;
+pub use nine_slice::*;
 pub use text_button::*;
 pub use text_input::*;
 
@@ -11,15 +13,19 @@ use crate::shaders::*;
 
 const UI_SIZE: f32 = 0.08;
 
-fn shadow_from_shape<'a>(shape: &'a [Vec2]) -> impl Iterator<Item = ShadowInstance> + 'a {
+fn shadow_from_shape(shape: &[Vec2], height: f32) -> impl Iterator<Item = ShadowInstance> + '_ {
     shape
         .windows(2)
-        .map(|vertices| ShadowInstance {
+        .map(move |vertices| ShadowInstance {
             a: vertices[0],
             b: vertices[1],
+            height,
+            layers: ShadowInstance::DEFAULT_LAYERS,
         })
         .chain(std::iter::once(ShadowInstance {
             a: *shape.last().unwrap(),
             b: shape[0],
+            height,
+            layers: ShadowInstance::DEFAULT_LAYERS,
         }))
 }
@@ -1,6 +1,7 @@
 use super::shadow_from_shape;
+use crate::layers::*;
 use crate::math::*;
-use crate::scenes::*;
+use crate::scene_graph::*;
 use crate::shaders::*;
 use crate::shapes::*;
 use crate::texture_atlas::*;
@@ -8,40 +9,34 @@ use crate::texture_atlas::*;
 macro_rules! block_object {
     ($Struct:ident, $SHAPE:ident, $image:ident) => {
         pub struct $Struct {
-            quad_id: usize,
-            shadow_id: [usize; $SHAPE.len()],
+            node: NodeId,
         }
 
         impl $Struct {
-            pub fn new(layers: &mut GameLayers, pos: Vec2) -> Self {
+            /// The block is drawn in the `quads` layer and casts shadows in the `ligths` layer
+            pub fn new(nodes: &mut SceneGraph, quads: LayerId, ligths: LayerId, pos: Vec2) -> Self {
                 let size = 0.3;
 
-                let quad_id = layers.blocks.buffer.push(QuadInstance::new_tex(
-                    pos,
-                    size,
-                    TextureAtlas::$image(),
-                ));
-
-                let mut shadow_id = [0; $SHAPE.len()];
-
-                for (i, mut shadow) in shadow_from_shape(&$SHAPE).enumerate() {
-                    shadow.a = shadow.a * size + pos;
-                    shadow.b = shadow.b * size + pos;
-                    shadow_id[i] = layers.ligths.add_shadow(shadow);
+                let node = nodes.add_node(None, Transform::new(pos));
+                nodes.add_quad(
+                    node,
+                    quads,
+                    QuadInstance::new_tex(Vec2::zero(), size, TextureAtlas::$image()),
+                );
+
+                // As tall as it is wide
+                for mut shadow in shadow_from_shape(&$SHAPE, size) {
+                    shadow.a *= size;
+                    shadow.b *= size;
+                    nodes.add_shadow(node, ligths, shadow);
                 }
 
-                Self { quad_id, shadow_id }
+                Self { node }
             }
 
             #[allow(unused)]
-            pub fn set_pos(&self, layers: &mut GameLayers, pos: Vec2) {
-                let quad = layers.blocks.buffer.get_mut(self.quad_id);
-                for shadow_id in self.shadow_id {
-                    let shadow = layers.ligths.get_shadow_mut(shadow_id);
-                    shadow.a = shadow.a - quad.pos + pos;
-                    shadow.b = shadow.b - quad.pos + pos;
-                }
-                quad.pos = pos;
+            pub fn set_pos(&self, nodes: &mut SceneGraph, pos: Vec2) {
+                nodes.set_pos(self.node, pos);
             }
         }
     };
@@ -0,0 +1,213 @@
+use super::*;
+use crate::layers::*;
+use crate::texture_atlas::*;
+
+/// Draws a `NineSliceView` as up to 9 quads,
+/// the borders keep their size while the center stretches to fill the rect
+pub struct NineSlice {
+    slice: NineSliceView,
+    /// World size of the untrimmed image height, scales the borders
+    scale: f32,
+    pos: Vec2,
+    size: Vec2,
+    color: u32,
+    /// Row major from the bottom left cell
+    quads: Vec<usize>,
+}
+
+impl NineSlice {
+    /// `pos` is the center of the rect
+    pub fn new(
+        layer: &mut QuadLayer,
+        slice: NineSliceView,
+        scale: f32,
+        pos: Vec2,
+        size: Vec2,
+    ) -> Self {
+        let mut nine_slice = Self {
+            slice,
+            scale,
+            pos,
+            size,
+            color: 0,
+            quads: Vec::with_capacity(9),
+        };
+
+        for quad in nine_slice.cells() {
+            nine_slice.quads.push(layer.buffer.push(quad));
+        }
+
+        nine_slice
+    }
+
+    /// Quads of the columns and rows with some size, an empty border has no quads
+    fn cells(&self) -> Vec<QuadInstance> {
+        let slice = &self.slice;
+        let view = slice.view;
+
+        let half_size = self.size * 0.5;
+        let min = self.pos - half_size + slice.margins_min * self.scale;
+        let max = self.pos + half_size - slice.margins_max * self.scale;
+
+        // Size of the trimmed image in units of the untrimmed height
+        let trimmed_size = view.aspect_ratio_x1() * view.trim_scale;
+
+        let edges = |min: f32, max: f32, inset_min: f32, inset_max: f32| {
+            let inner_min = (min + inset_min * self.scale).min(max);
+            let inner_max = (max - inset_max * self.scale).max(inner_min);
+            [min, inner_min, inner_max, max]
+        };
+        let tex_edges = |pos: f32, size: f32, trimmed: f32, inset_min: f32, inset_max: f32| {
+            [
+                pos,
+                pos + inset_min / trimmed * size,
+                pos + size - inset_max / trimmed * size,
+                pos + size,
+            ]
+        };
+
+        let x = edges(min.x, max.x, slice.insets_min.x, slice.insets_max.x);
+        let y = edges(min.y, max.y, slice.insets_min.y, slice.insets_max.y);
+        let u = tex_edges(
+            view.pos.x,
+            view.size.x,
+            trimmed_size.x,
+            slice.insets_min.x,
+            slice.insets_max.x,
+        );
+        // The texture y axis points down
+        let v = tex_edges(
+            view.pos.y + view.size.y,
+            -view.size.y,
+            trimmed_size.y,
+            slice.insets_min.y,
+            slice.insets_max.y,
+        );
+
+        let columns = Self::non_empty(slice.insets_min.x, slice.insets_max.x);
+        let rows = Self::non_empty(slice.insets_min.y, slice.insets_max.y);
+
+        let mut cells = Vec::with_capacity(9);
+        for &row in &rows {
+            for &column in &columns {
+                let cell_min = Vec2::new(x[column], y[row]);
+                let cell_max = Vec2::new(x[column + 1], y[row + 1]);
+
+                cells.push(QuadInstance {
+                    pos: (cell_min + cell_max) * 0.5,
+                    size: cell_max - cell_min,
+                    tex_pos: Vec2::new(u[column], v[row + 1]),
+                    tex_size: Vec2::new(u[column + 1] - u[column], v[row] - v[row + 1]),
+                    page: view.page,
+                    color: self.color,
+                    ..QuadInstance::new_color(Vec2::zero(), Vec2::zero(), 0)
+                });
+            }
+        }
+        cells
+    }
+
+    fn non_empty(inset_min: f32, inset_max: f32) -> Vec<usize> {
+        [inset_min > 0., true, inset_max > 0.]
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, non_empty)| non_empty.then_some(i))
+            .collect()
+    }
+
+    /// Color mixed over the texture, see `QuadInstance::color`
+    pub fn set_color(&mut self, layer: &mut QuadLayer, color: u32) {
+        if self.color != color {
+            self.color = color;
+            for quad in &self.quads {
+                layer.buffer.get_mut(*quad).color = color;
+            }
+        }
+    }
+
+    pub fn contains(&self, pos: Vec2) -> bool {
+        let half_size = self.size * 0.5;
+        !(pos.x < self.pos.x - half_size.x
+            || self.pos.x + half_size.x < pos.x
+            || pos.y < self.pos.y - half_size.y
+            || self.pos.y + half_size.y < pos.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nine_slice(insets_min: Vec2, insets_max: Vec2, size: Vec2) -> NineSlice {
+        NineSlice {
+            slice: NineSliceView {
+                view: TextureAtlas::view_text_button(),
+                insets_min,
+                insets_max,
+                margins_min: Vec2::zero(),
+                margins_max: Vec2::zero(),
+            },
+            scale: 2.,
+            pos: Vec2::zero(),
+            size,
+            color: 0,
+            quads: Vec::new(),
+        }
+    }
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!((a - b).length() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn borders_keep_their_size() {
+        let slice = nine_slice(Vec2::new(0.2, 0.1), Vec2::new(0.3, 0.2), Vec2::new(4., 2.));
+        let cells = slice.cells();
+        assert_eq!(cells.len(), 9);
+
+        // Bottom left, center and top right
+        assert_near(cells[0].size, Vec2::new(0.4, 0.2));
+        assert_near(cells[0].pos, Vec2::new(-1.8, -0.9));
+        assert_near(cells[4].size, Vec2::new(3., 1.4));
+        assert_near(cells[4].pos, Vec2::new(-0.1, -0.1));
+        assert_near(cells[8].size, Vec2::new(0.6, 0.4));
+        assert_near(cells[8].pos, Vec2::new(1.7, 0.8));
+
+        // The cells split the texture of the view
+        let view = slice.slice.view;
+        let width: f32 = cells[..3].iter().map(|cell| cell.tex_size.x).sum();
+        let height: f32 = cells.iter().step_by(3).map(|cell| cell.tex_size.y).sum();
+        assert_near(Vec2::new(width, height), view.size);
+        assert_near(cells[6].tex_pos, view.pos);
+    }
+
+    #[test]
+    fn empty_borders_have_no_cells() {
+        let slice = nine_slice(Vec2::zero(), Vec2::new(0.3, 0.), Vec2::new(4., 2.));
+        let cells = slice.cells();
+        assert_eq!(cells.len(), 2);
+        assert_near(cells[0].size, Vec2::new(3.4, 2.));
+        assert_near(cells[1].size, Vec2::new(0.6, 2.));
+    }
+
+    #[test]
+    fn small_rects_shrink_the_center_first() {
+        let slice = nine_slice(Vec2::new(0.2, 0.), Vec2::new(0.3, 0.), Vec2::new(0.5, 1.));
+        let widths: Vec<f32> = slice.cells().iter().map(|cell| cell.size.x).collect();
+        assert_eq!(widths.len(), 3);
+        assert!((widths[0] - 0.4).abs() < 1e-6);
+        assert_eq!(widths[1], 0.);
+        assert!((widths[2] - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn margins_shrink_the_rect() {
+        let mut slice = nine_slice(Vec2::zero(), Vec2::zero(), Vec2::new(4., 2.));
+        slice.slice.margins_min = Vec2::new(0.1, 0.);
+        slice.slice.margins_max = Vec2::new(0., 0.2);
+        let cells = slice.cells();
+        assert_eq!(cells.len(), 1);
+        assert_near(cells[0].size, Vec2::new(3.8, 1.6));
+        assert_near(cells[0].pos, Vec2::new(0.1, -0.2));
+    }
+}
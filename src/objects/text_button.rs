@@ -5,7 +5,7 @@ use crate::layers::*;
 use crate::texture_atlas::*;
 
 pub struct TextButton {
-    back_quad: usize,
+    back: NineSlice,
     text_quads: Vec<usize>,
 }
 
@@ -22,18 +22,20 @@ impl TextButton {
     pub fn new(desc: TextButtonDescriptor) -> Self {
         const MARGIN: f32 = 1.3;
 
-        let tex_view = TextureAtlas::view_text_button();
+        let text_width = FONT.width(desc.text) * UI_SIZE;
 
-        let back_quad = desc.layer.buffer.push(QuadInstance {
-            pos: desc.pos,
-            size: tex_view.aspect_ratio_x1() * UI_SIZE * MARGIN,
-            color: Self::COLOR,
-            angle: 0.,
-            tex_pos: tex_view.pos,
-            tex_size: tex_view.size,
-        });
+        let slice = TextureAtlas::slice_text_button();
+        let height = UI_SIZE * MARGIN;
+        let borders = (slice.insets_min.x + slice.insets_max.x) * height;
 
-        let text_width = FONT.width(desc.text) * UI_SIZE;
+        let mut back = NineSlice::new(
+            desc.layer,
+            slice,
+            height,
+            desc.pos,
+            Vec2::new(text_width + borders + UI_SIZE, height),
+        );
+        back.set_color(desc.layer, Self::COLOR);
 
         let text_quads = FONT
             .write(desc.text, UI_SIZE)
@@ -45,34 +47,18 @@ impl TextButton {
             })
             .collect();
 
-        Self {
-            back_quad,
-            text_quads,
-        }
-    }
-
-    fn hitbox_check(&self, pos: Vec2, layer: &QuadInstance) -> bool {
-        let half_size = layer.size * 0.5;
-        !(pos.x < layer.pos.x - half_size.x
-            || layer.pos.x + half_size.x < pos.x
-            || pos.y < layer.pos.y - half_size.y
-            || layer.pos.y + half_size.y < pos.y)
+        Self { back, text_quads }
     }
 }
 
 impl InputEventHandler<QuadLayer> for TextButton {
     fn mouse_moved(&mut self, mouse: &Mouse, layer: &mut QuadLayer) {
-        let quad = layer.buffer.get_ref(self.back_quad);
-
-        let color = if self.hitbox_check(mouse.pos, quad) {
+        let color = if self.back.contains(mouse.pos) {
             Self::HOVER_COLOR
         } else {
             Self::COLOR
         };
 
-        if quad.color != color {
-            let quad = layer.buffer.get_mut(self.back_quad);
-            quad.color = color;
-        }
+        self.back.set_color(layer, color);
     }
 }
@@ -2,9 +2,10 @@ use super::*;
 use crate::font::*;
 use crate::input::*;
 use crate::layers::*;
+use crate::texture_atlas::*;
 
 pub struct TextInput {
-    back_quad: usize,
+    back: NineSlice,
     text_quads: Vec<usize>,
     pos: Vec2,
     text: String,
@@ -24,15 +25,18 @@ impl TextInput {
     pub fn new(desc: TextInputDescriptor) -> Self {
         const MARGIN: f32 = 1.3;
 
-        let back_quad = desc.layer.buffer.push(QuadInstance::new_color(
+        let mut back = NineSlice::new(
+            desc.layer,
+            TextureAtlas::slice_text_button(),
+            UI_SIZE * MARGIN,
             desc.pos,
             Vec2::new(7., 1.) * UI_SIZE * MARGIN,
-            Self::COLOR,
-        ));
+        );
+        back.set_color(desc.layer, Self::COLOR);
 
         let mut text_input = Self {
             pos: desc.pos,
-            back_quad,
+            back,
             text_quads: Vec::new(),
             text: String::new(),
             placeholder: String::from(desc.placeholder),
@@ -41,14 +45,6 @@ impl TextInput {
         text_input
     }
 
-    fn hitbox_check(&self, pos: Vec2, layer: &QuadInstance) -> bool {
-        let half_size = layer.size * 0.5;
-        !(pos.x < layer.pos.x - half_size.x
-            || layer.pos.x + half_size.x < pos.x
-            || pos.y < layer.pos.y - half_size.y
-            || layer.pos.y + half_size.y < pos.y)
-    }
-
     fn update_text_quads(&mut self, layer: &mut QuadLayer) {
         let (text, color) = if self.text.is_empty() {
             (&self.placeholder, 0x888888FF)
@@ -75,28 +71,21 @@ impl TextInput {
             i += 1;
         }
 
-        // Make the remaining quads not visible
-        for i in i..self.text_quads.len() {
-            let quad_index = self.text_quads[i];
-            layer.buffer.get_mut(quad_index).size.x = 0.;
+        for quad_index in self.text_quads.drain(i..) {
+            layer.buffer.remove(quad_index);
         }
     }
 }
 
 impl InputEventHandler<QuadLayer> for TextInput {
     fn mouse_moved(&mut self, mouse: &Mouse, layer: &mut QuadLayer) {
-        let quad = layer.buffer.get_ref(self.back_quad);
-
-        let color = if self.hitbox_check(mouse.pos, quad) {
+        let color = if self.back.contains(mouse.pos) {
             Self::HOVER_COLOR
         } else {
             Self::COLOR
         };
 
-        if quad.color != color {
-            let quad = layer.buffer.get_mut(self.back_quad);
-            quad.color = color;
-        }
+        self.back.set_color(layer, color);
     }
 
     fn typed_text(&mut self, text: &str, layer: &mut QuadLayer) {
@@ -0,0 +1,297 @@
+use crate::math::*;
+use crate::shaders::*;
+use crate::texture_atlas::*;
+use std::ops::Range;
+
+/// Piecewise linear function of the normalized age of a particle
+#[derive(Clone, Debug)]
+pub struct Curve {
+    keys: Vec<(f32, f32)>,
+}
+
+/// Piecewise linear color (0xRRGGBBAA) over the normalized age of a particle
+#[derive(Clone, Debug)]
+pub struct ColorCurve {
+    keys: Vec<(f32, u32)>,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub enum EmitterShape {
+    Point,
+    /// Segment relative to the emitter position
+    Line(Vec2, Vec2),
+    /// Area inside the polygon, relative to the emitter position
+    Polygon(Vec<Vec2>),
+}
+
+#[derive(Clone, Debug)]
+pub struct ParticleGlow {
+    /// Packed with `LigthUniform::color`, faded with the alpha of the particles
+    pub color: u32,
+    /// Distance reached by the glow of each particle
+    pub radius: f32,
+}
+
+#[derive(Clone, Debug)]
+pub struct EmitterDescriptor {
+    pub shape: EmitterShape,
+    /// Particles spawned each second
+    pub rate: f32,
+    /// Seconds, picked at random for each particle
+    pub lifetime: Range<f32>,
+    pub speed: Range<f32>,
+    /// Angle of the velocity in radians, counterclockwise from the x axis
+    pub direction: f32,
+    /// Max deviation from `direction` in radians
+    pub spread: f32,
+    pub gravity: Vec2,
+    /// Fraction of the velocity lost each second
+    pub drag: f32,
+    /// Width of the particles over their life
+    pub size: Curve,
+    /// Tint of the particles over their life
+    pub color: ColorCurve,
+    /// Frames played over the life of the particles, solid quads when empty
+    pub sprites: &'static [Sprite],
+    pub glow: Option<ParticleGlow>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Particle {
+    pos: Vec2,
+    velocity: Vec2,
+    age: f32,
+    lifetime: f32,
+}
+
+/// Particles simulated on the cpu, see `ParticleLayer`
+pub struct ParticleEmitter {
+    pub pos: Vec2,
+    /// When false no particles are spawned, the alive ones finish their life
+    pub emitting: bool,
+    pub desc: EmitterDescriptor,
+    particles: Vec<Particle>,
+    /// Fraction of a particle that is waiting to be spawned
+    pending: f32,
+    rng: Rng,
+}
+
+impl Curve {
+    pub fn constant(value: f32) -> Self {
+        Self {
+            keys: vec![(0., value)],
+        }
+    }
+
+    pub fn linear(start: f32, end: f32) -> Self {
+        Self {
+            keys: vec![(0., start), (1., end)],
+        }
+    }
+
+    pub fn sample(&self, time: f32) -> f32 {
+        sample_keys(&self.keys, time, |a, b, t| a + (b - a) * t)
+    }
+}
+
+impl ColorCurve {
+    pub fn constant(color: u32) -> Self {
+        Self {
+            keys: vec![(0., color)],
+        }
+    }
+
+    /// `keys` are `(time, color)` with increasing times from 0 to 1
+    pub fn new(keys: &[(f32, u32)]) -> Self {
+        assert!(!keys.is_empty(), "A curve needs at least one key");
+        Self {
+            keys: keys.to_vec(),
+        }
+    }
+
+    pub fn sample(&self, time: f32) -> u32 {
+        sample_keys(&self.keys, time, lerp_color)
+    }
+}
+
+fn sample_keys<T: Copy>(keys: &[(f32, T)], time: f32, lerp: impl Fn(T, T, f32) -> T) -> T {
+    let next = keys.partition_point(|(key_time, _)| *key_time <= time);
+    if next == 0 {
+        return keys[0].1;
+    }
+    if next == keys.len() {
+        return keys[next - 1].1;
+    }
+
+    let (start_time, start) = keys[next - 1];
+    let (end_time, end) = keys[next];
+    lerp(start, end, (time - start_time) / (end_time - start_time))
+}
+
+/// Interpolates each channel of two 0xRRGGBBAA colors
+fn lerp_color(a: u32, b: u32, t: f32) -> u32 {
+    let mut color = 0;
+    for shift in [0, 8, 16, 24] {
+        let a = ((a >> shift) & 0xFF) as f32;
+        let b = ((b >> shift) & 0xFF) as f32;
+        color |= ((a + (b - a) * t).round() as u32 & 0xFF) << shift;
+    }
+    color
+}
+
+impl EmitterShape {
+    fn sample(&self, rng: &mut Rng) -> Vec2 {
+        match self {
+            EmitterShape::Point => Vec2::zero(),
+            EmitterShape::Line(a, b) => *a + (*b - *a) * rng.next_f32(),
+            EmitterShape::Polygon(points) => {
+                if points.is_empty() {
+                    return Vec2::zero();
+                }
+
+                let (mut min, mut max) = (points[0], points[0]);
+                for point in points {
+                    min = Vec2::new(min.x.min(point.x), min.y.min(point.y));
+                    max = Vec2::new(max.x.max(point.x), max.y.max(point.y));
+                }
+
+                // Rejection sampling over the bounding box
+                for _ in 0..32 {
+                    let pos = Vec2::new(rng.range(min.x..max.x), rng.range(min.y..max.y));
+                    if polygon_contains(points, pos) {
+                        return pos;
+                    }
+                }
+                points[rng.next_u32() as usize % points.len()]
+            }
+        }
+    }
+}
+
+/// Even-odd rule
+fn polygon_contains(points: &[Vec2], pos: Vec2) -> bool {
+    let mut inside = false;
+    let mut prev = points[points.len() - 1];
+    for &point in points {
+        if (point.y > pos.y) != (prev.y > pos.y)
+            && pos.x < point.x + (prev.x - point.x) * (pos.y - point.y) / (prev.y - point.y)
+        {
+            inside = !inside;
+        }
+        prev = point;
+    }
+    inside
+}
+
+impl EmitterDescriptor {
+    /// White square particles that stay where they are spawned
+    pub fn new(rate: f32, lifetime: Range<f32>) -> Self {
+        Self {
+            shape: EmitterShape::Point,
+            rate,
+            lifetime,
+            speed: 0.0..0.0,
+            direction: 0.,
+            spread: 0.,
+            gravity: Vec2::zero(),
+            drag: 0.,
+            size: Curve::constant(0.02),
+            color: ColorCurve::constant(0xFFFFFFFF),
+            sprites: &[],
+            glow: None,
+        }
+    }
+}
+
+impl ParticleEmitter {
+    /// Longest time spawned in one update, a long frame would spawn its particles at once
+    const MAX_SPAWN_TIME: f32 = 0.1;
+
+    /// Emitters with the same `seed` spawn the same particles
+    pub fn new(pos: Vec2, desc: EmitterDescriptor, seed: u32) -> Self {
+        Self {
+            pos,
+            emitting: true,
+            desc,
+            particles: Vec::new(),
+            pending: 0.,
+            rng: Rng::new(seed),
+        }
+    }
+
+    fn spawn(&mut self) {
+        let desc = &self.desc;
+        let pos = self.pos + desc.shape.sample(&mut self.rng);
+        let angle = desc.direction + self.rng.range(-desc.spread..desc.spread);
+        let speed = self.rng.range(desc.speed.clone());
+        let lifetime = self.rng.range(desc.lifetime.clone());
+
+        self.particles.push(Particle {
+            pos,
+            velocity: Vec2::new(speed, 0.).rotate(angle),
+            age: 0.,
+            lifetime,
+        });
+    }
+
+    /// Advances the simulation `delta_time` seconds
+    pub fn update(&mut self, delta_time: f32) {
+        if self.emitting {
+            self.pending += self.desc.rate * delta_time.min(Self::MAX_SPAWN_TIME);
+            while self.pending >= 1. {
+                self.pending -= 1.;
+                self.spawn();
+            }
+        }
+
+        let gravity = self.desc.gravity * delta_time;
+        let drag = (1. - self.desc.drag * delta_time).max(0.);
+        for particle in &mut self.particles {
+            particle.age += delta_time;
+            particle.velocity = (particle.velocity + gravity) * drag;
+            particle.pos += particle.velocity * delta_time;
+        }
+        self.particles
+            .retain(|particle| particle.age < particle.lifetime);
+    }
+
+    /// Normalized age of each particle
+    fn lives(&self) -> impl Iterator<Item = (&Particle, f32)> {
+        self.particles
+            .iter()
+            .map(|particle| (particle, (particle.age / particle.lifetime).min(1.)))
+    }
+
+    pub fn quads(&self) -> impl Iterator<Item = QuadInstance> + '_ {
+        let desc = &self.desc;
+        self.lives().map(move |(particle, life)| {
+            let width = desc.size.sample(life);
+            let mut quad = if desc.sprites.is_empty() {
+                QuadInstance::new_color(particle.pos, Vec2::new(width, width), 0xFFFFFFFF)
+            } else {
+                let frame = (life * desc.sprites.len() as f32) as usize;
+                let sprite = desc.sprites[frame.min(desc.sprites.len() - 1)];
+                QuadInstance::new_tex(particle.pos, width, sprite.view())
+            };
+            quad.tint = desc.color.sample(life);
+            quad
+        })
+    }
+
+    /// Glows of each particle, not occluded by the shadows
+    pub fn glows(&self) -> impl Iterator<Item = GlowInstance> + '_ {
+        self.desc.glow.iter().flat_map(move |glow| {
+            self.lives().map(move |(particle, life)| GlowInstance {
+                pos: particle.pos,
+                radius: glow.radius,
+                color: glow.color,
+                intensity: alpha(self.desc.color.sample(life)),
+            })
+        })
+    }
+}
+
+fn alpha(color: u32) -> f32 {
+    (color & 0xFF) as f32 / 255.
+}
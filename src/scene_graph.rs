@@ -0,0 +1,444 @@
+use crate::layers::*;
+use crate::math::*;
+use crate::shaders::*;
+use crate::wgpu_components::*;
+
+/// Placement of a node relative to its parent
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub pos: Vec2,
+    /// Clockwise in radians, like `QuadInstance::angle`
+    pub angle: f32,
+    /// A negative scale flips the quads
+    pub scale: Vec2,
+}
+
+/// Stops matching the slot once the node is removed, even if the slot is reused
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+/// Transform hierarchy of nodes that own quads, shadows and ligths.
+/// The attachments are defined relative to their node, and written to the layers
+/// on `sync` when any transform or visibility above them changes
+#[derive(Default)]
+pub struct SceneGraph {
+    nodes: Vec<Slot>,
+    /// Layer slots of the removed nodes, freed on the next sync
+    removed: Vec<Attachments>,
+    needs_sync: bool,
+}
+
+#[derive(Default)]
+struct Slot {
+    /// Increased when the node is removed
+    generation: u32,
+    node: Option<Node>,
+}
+
+struct Node {
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    transform: Transform,
+    visible: bool,
+    needs_sync: bool,
+    attachments: Attachments,
+}
+
+#[derive(Default)]
+struct Attachments {
+    quads: Vec<Attached<QuadInstance>>,
+    shadows: Vec<Attached<ShadowInstance>>,
+    ligths: Vec<Attached<NodeLigth>>,
+}
+
+struct Attached<T> {
+    layer: LayerId,
+    local: T,
+    /// Slot in the layer, `None` until the next sync
+    index: Option<usize>,
+}
+
+#[derive(Copy, Clone)]
+struct NodeLigth {
+    pos: Vec2,
+    /// Packed with `LigthUniform::color`
+    color: u32,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self {
+        pos: Vec2::zero(),
+        angle: 0.,
+        scale: Vec2::new(1., 1.),
+    };
+
+    pub fn new(pos: Vec2) -> Self {
+        Self {
+            pos,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Maps a point of this space to the space of the parent
+    pub fn apply(&self, point: Vec2) -> Vec2 {
+        (point * self.scale).rotate(-self.angle) + self.pos
+    }
+
+    /// `child` placed in the space of the parent of `self`.
+    /// Non uniform scales are only exact when the child is not rotated
+    pub fn then(&self, child: &Transform) -> Transform {
+        Transform {
+            pos: self.apply(child.pos),
+            angle: self.angle + self.child_angle(child.angle),
+            scale: self.scale * child.scale,
+        }
+    }
+
+    /// A mirror reverses the rotations inside it
+    fn child_angle(&self, angle: f32) -> f32 {
+        if self.scale.x * self.scale.y < 0. {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
+
+impl SceneGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, parent: Option<NodeId>, transform: Transform) -> NodeId {
+        let node = Node {
+            parent,
+            children: Vec::new(),
+            transform,
+            visible: true,
+            needs_sync: true,
+            attachments: Attachments::default(),
+        };
+
+        let index = match self.nodes.iter().position(|slot| slot.node.is_none()) {
+            Some(index) => index,
+            None => {
+                self.nodes.push(Slot::default());
+                self.nodes.len() - 1
+            }
+        };
+        let slot = &mut self.nodes[index];
+        slot.node = Some(node);
+        let id = NodeId {
+            index,
+            generation: slot.generation,
+        };
+
+        if let Some(parent) = parent {
+            self.node_mut(parent).children.push(id);
+        }
+        self.needs_sync = true;
+        id
+    }
+
+    /// Removes the node with all its children and attachments
+    pub fn remove_node(&mut self, id: NodeId) {
+        let slot = &mut self.nodes[id.index];
+        assert_eq!(slot.generation, id.generation, "Node already removed");
+        let node = slot.node.take().unwrap();
+        slot.generation = slot.generation.wrapping_add(1);
+        if let Some(parent) = node.parent {
+            self.node_mut(parent).children.retain(|child| *child != id);
+        }
+        for child in node.children {
+            // The child must not unlink itself from the removed parent
+            self.node_mut(child).parent = None;
+            self.remove_node(child);
+        }
+        self.removed.push(node.attachments);
+        self.needs_sync = true;
+    }
+
+    pub fn set_pos(&mut self, id: NodeId, pos: Vec2) {
+        self.mark(id).transform.pos = pos;
+    }
+
+    pub fn set_angle(&mut self, id: NodeId, angle: f32) {
+        self.mark(id).transform.angle = angle;
+    }
+
+    /// Hidden nodes hide all their children
+    pub fn set_visible(&mut self, id: NodeId, visible: bool) {
+        self.mark(id).visible = visible;
+    }
+
+    pub fn is_visible(&self, id: NodeId) -> bool {
+        let node = self.node(id);
+        node.visible && node.parent.is_none_or(|parent| self.is_visible(parent))
+    }
+
+    /// `quad` is relative to the node and drawn in the quads `layer`.
+    /// Returns the index of the quad in the node
+    pub fn add_quad(&mut self, id: NodeId, layer: LayerId, quad: QuadInstance) -> usize {
+        let quads = &mut self.mark(id).attachments.quads;
+        quads.push(Attached {
+            layer,
+            local: quad,
+            index: None,
+        });
+        quads.len() - 1
+    }
+
+    /// `shadow` is relative to the node and casted in the ligths `layer`.
+    /// Returns the index of the shadow in the node
+    pub fn add_shadow(&mut self, id: NodeId, layer: LayerId, shadow: ShadowInstance) -> usize {
+        let shadows = &mut self.mark(id).attachments.shadows;
+        shadows.push(Attached {
+            layer,
+            local: shadow,
+            index: None,
+        });
+        shadows.len() - 1
+    }
+
+    /// `pos` is relative to the node, the ligth is added to the ligths `layer`.
+    /// Returns the index of the ligth in the node
+    pub fn add_ligth(&mut self, id: NodeId, layer: LayerId, pos: Vec2, color: u32) -> usize {
+        let ligths = &mut self.mark(id).attachments.ligths;
+        ligths.push(Attached {
+            layer,
+            local: NodeLigth { pos, color },
+            index: None,
+        });
+        ligths.len() - 1
+    }
+
+    pub fn set_ligth_color(&mut self, id: NodeId, ligth: usize, color: u32) {
+        self.mark(id).attachments.ligths[ligth].local.color = color;
+    }
+
+    /// Writes the changed nodes to the layers,
+    /// skipping the attachments whose layer has another kind of content
+    pub fn sync(&mut self, ctx: &WgpuContext, layers: &mut LayerStack) {
+        if !self.needs_sync {
+            return;
+        }
+        self.needs_sync = false;
+
+        for attachments in std::mem::take(&mut self.removed) {
+            attachments.remove(layers);
+        }
+
+        for index in 0..self.nodes.len() {
+            let slot = &self.nodes[index];
+            if matches!(&slot.node, Some(node) if node.parent.is_none()) {
+                let id = NodeId {
+                    index,
+                    generation: slot.generation,
+                };
+                self.sync_node(ctx, layers, id, Transform::IDENTITY, true, false);
+            }
+        }
+    }
+
+    fn sync_node(
+        &mut self,
+        ctx: &WgpuContext,
+        layers: &mut LayerStack,
+        id: NodeId,
+        parent: Transform,
+        parent_visible: bool,
+        parent_changed: bool,
+    ) {
+        let node = self.node_mut(id);
+        let world = parent.then(&node.transform);
+        let visible = parent_visible && node.visible;
+        let changed = parent_changed || node.needs_sync;
+
+        if changed {
+            node.needs_sync = false;
+            node.attachments.write(ctx, layers, &world, visible);
+        }
+
+        for i in 0..self.node(id).children.len() {
+            let child = self.node(id).children[i];
+            self.sync_node(ctx, layers, child, world, visible, changed);
+        }
+    }
+
+    fn node(&self, id: NodeId) -> &Node {
+        let slot = &self.nodes[id.index];
+        assert_eq!(slot.generation, id.generation, "Node removed");
+        slot.node.as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut Node {
+        let slot = &mut self.nodes[id.index];
+        assert_eq!(slot.generation, id.generation, "Node removed");
+        slot.node.as_mut().unwrap()
+    }
+
+    /// The node and its children are written on the next sync
+    fn mark(&mut self, id: NodeId) -> &mut Node {
+        self.needs_sync = true;
+        let node = self.node_mut(id);
+        node.needs_sync = true;
+        node
+    }
+}
+
+impl Attachments {
+    fn write(
+        &mut self,
+        ctx: &WgpuContext,
+        layers: &mut LayerStack,
+        world: &Transform,
+        visible: bool,
+    ) {
+        for quad in &mut self.quads {
+            let local = quad.local;
+            let mut instance = local;
+            instance.pos = world.apply(local.pos);
+            instance.angle = world.angle + world.child_angle(local.angle);
+            instance.size = Vec2::new(
+                local.size.x * world.scale.x.abs(),
+                local.size.y * world.scale.y.abs(),
+            );
+            if world.scale.x < 0. {
+                instance.flags ^= QuadInstance::FLIP_X;
+            }
+            if world.scale.y < 0. {
+                instance.flags ^= QuadInstance::FLIP_Y;
+            }
+            if !visible {
+                instance.disable();
+            }
+
+            let Some(layer) = layers.quads(quad.layer) else {
+                continue;
+            };
+            match quad.index {
+                Some(index) => *layer.buffer.get_mut(index) = instance,
+                None => quad.index = Some(layer.buffer.push(instance)),
+            }
+        }
+
+        for shadow in &mut self.shadows {
+            let instance = if visible {
+                ShadowInstance {
+                    a: world.apply(shadow.local.a),
+                    b: world.apply(shadow.local.b),
+                    height: shadow.local.height,
+                    layers: shadow.local.layers,
+                }
+            } else {
+                ShadowInstance::default()
+            };
+
+            let Some(layer) = layers.ligths(shadow.layer) else {
+                continue;
+            };
+            match shadow.index {
+                Some(index) => *layer.get_shadow_mut(index) = instance,
+                None => shadow.index = Some(layer.add_shadow(instance)),
+            }
+        }
+
+        for ligth in &mut self.ligths {
+            let pos = world.apply(ligth.local.pos);
+            let color = if visible { ligth.local.color } else { 0 };
+
+            let Some(layer) = layers.ligths(ligth.layer) else {
+                continue;
+            };
+            match ligth.index {
+                Some(index) => {
+                    let uniform = layer.get_ligth_mut(index);
+                    uniform.data.pos = pos;
+                    uniform.data.color = color;
+                    uniform.needs_update = true;
+                }
+                None => ligth.index = Some(layer.add_ligth(ctx, pos, color)),
+            }
+        }
+    }
+
+    /// Frees the slots of the attachments in their layers
+    fn remove(self, layers: &mut LayerStack) {
+        for quad in self.quads {
+            if let (Some(index), Some(layer)) = (quad.index, layers.quads(quad.layer)) {
+                layer.buffer.remove(index);
+            }
+        }
+        for shadow in self.shadows {
+            if let (Some(index), Some(layer)) = (shadow.index, layers.ligths(shadow.layer)) {
+                layer.remove_shadow(index);
+            }
+        }
+        for ligth in self.ligths {
+            if let (Some(index), Some(layer)) = (ligth.index, layers.ligths(ligth.layer)) {
+                layer.remove_ligth(index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!((a - b).length() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn children_follow_the_parent() {
+        let parent = Transform {
+            pos: Vec2::new(1., 2.),
+            angle: FRAC_PI_2,
+            scale: Vec2::new(2., 2.),
+        };
+        let child = Transform {
+            angle: 0.5,
+            ..Transform::new(Vec2::new(1., 0.))
+        };
+
+        let world = parent.then(&child);
+        // A quarter turn clockwise takes the x axis down
+        assert_near(world.pos, Vec2::new(1., 0.));
+        assert!((world.angle - (FRAC_PI_2 + 0.5)).abs() < 1e-6);
+        assert_eq!(world.scale, Vec2::new(2., 2.));
+
+        assert_near(world.apply(Vec2::zero()), parent.apply(child.pos));
+        assert_near(
+            world.apply(Vec2::new(0., 1.)),
+            parent.apply(child.apply(Vec2::new(0., 1.))),
+        );
+    }
+
+    #[test]
+    fn mirrors_reverse_the_child_rotation() {
+        let mirror = Transform {
+            scale: Vec2::new(-1., 1.),
+            ..Transform::IDENTITY
+        };
+        let child = Transform {
+            angle: 0.5,
+            ..Transform::new(Vec2::new(1., 0.))
+        };
+
+        let world = mirror.then(&child);
+        assert_near(world.pos, Vec2::new(-1., 0.));
+        assert_eq!(world.angle, -0.5);
+        assert_eq!(world.scale, Vec2::new(-1., 1.));
+
+        // Two mirrors cancel out
+        let world = mirror.then(&mirror).then(&child);
+        assert_near(world.pos, Vec2::new(1., 0.));
+        assert_eq!(world.angle, 0.5);
+        assert_eq!(world.scale, Vec2::new(1., 1.));
+    }
+}
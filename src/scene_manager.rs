@@ -1,3 +1,4 @@
+use std::time::Instant;
 use winit::event::WindowEvent;
 
 use crate::input::*;
@@ -8,25 +9,27 @@ use crate::shaders::Shaders;
 use crate::ErrResult;
 use crate::WgpuContext;
 
-pub struct SceneManager {
-    scene: Lobby,
+pub struct SceneManager<S> {
+    scene: S,
 
     shaders: Shaders,
     pipeline: LigthPipeline,
     input: Input,
+    last_frame: Instant,
 }
 
-impl SceneManager {
+impl<S: Scene> SceneManager<S> {
     pub fn new(ctx: &WgpuContext, width: u32, height: u32) -> ErrResult<Self> {
         let pipeline = LigthPipeline::new(ctx, width, height);
         let shaders = Shaders::new(ctx, &pipeline.textures)?;
         let input = Input::new();
 
         Ok(Self {
-            scene: Lobby::new(ctx),
+            scene: S::new(ctx),
             shaders,
             pipeline,
             input,
+            last_frame: Instant::now(),
         })
     }
 
@@ -39,8 +42,15 @@ impl SceneManager {
         self.input.propagate_events(&mut self.scene, &mut ());
         self.input.update();
 
+        let now = Instant::now();
+        let delta_time = (now - self.last_frame).as_secs_f32();
+        self.last_frame = now;
+        Scene::update(&mut self.scene, delta_time);
+
+        let unshadowed = self.scene.prepare(ctx);
+
         let mut ligth_frame = self.pipeline.start_frame(&ctx, target);
-        let mut ligth_pass = ligth_frame.create_render_pass();
+        let mut ligth_pass = ligth_frame.create_render_pass(unshadowed);
 
         self.scene.draw(&mut ligth_pass, &self.shaders);
 
@@ -0,0 +1,502 @@
+use super::Scene;
+use crate::animation::*;
+use crate::camera::*;
+use crate::input::*;
+use crate::layers::*;
+use crate::ligth_pipeline::*;
+use crate::math::*;
+use crate::particles::*;
+use crate::scene_graph::*;
+use crate::shaders::*;
+use crate::texture_atlas::*;
+use crate::wgpu_components::*;
+
+/// Showcase of the renderer features, run with `cargo run --example demo`.
+///
+/// Keys:
+/// - `w` `a` `s` `d` move the camera, `+` `-` zoom, `q` `e` rotate and `x` shakes it
+/// - `b` hides or shows the background and `t` tiles it with the next sprite
+/// - `l` hides or shows the lantern and `r` rebuilds it
+/// - `o` switches the players between sorting by y or only by z
+/// - `c` changes the caps of the aim line and `g` shows the debug outlines
+/// - `f` plays the bow charge back and forth, once or in a loop
+pub struct Demo {
+    layers: LayerStack,
+    nodes: SceneGraph,
+    camera: usize,
+    ui_camera: usize,
+    background: LayerId,
+    ligths: LayerId,
+    players: LayerId,
+    shapes: LayerId,
+    lines: LayerId,
+    ui: LayerId,
+
+    ligth: usize,
+    tiles: usize,
+    tile_sprite: Sprite,
+    bow: AnimatedSprite,
+    aim_line: usize,
+    aim_target: Vec2,
+    aim_cap: LineCap,
+    /// Lines of the debug outlines, empty when hidden
+    debug_lines: Vec<usize>,
+    /// Points to the embers when they are off the screen
+    arrow: usize,
+    lantern: Lantern,
+    lantern_trail: usize,
+    time: f32,
+}
+
+/// Lamp orbiting around a point, one tree of nodes
+struct Lantern {
+    root: NodeId,
+    /// Holds the quad and the ligth
+    lamp: NodeId,
+}
+
+impl Demo {
+    const BOW_POS: Vec2 = Vec2::new(-0.5, 0.);
+    const EMBERS_POS: Vec2 = Vec2::new(0.6, -0.45);
+    const LANTERN_POS: Vec2 = Vec2::new(0.9, 0.2);
+    /// Distance from the lamp to the center of its orbit
+    const LANTERN_RADIUS: f32 = 0.35;
+    const LANTERN_COLOR: (u16, u16, u16) = (255, 190, 110);
+
+    fn aim_style(cap: LineCap) -> LineStyle {
+        LineStyle {
+            cap,
+            dash: Vec2::new(0.04, 0.03),
+            ..LineStyle::new(0.012)
+        }
+    }
+
+    /// Guide from the bow to `target`, fading out
+    fn aim_points(target: Vec2) -> [LinePoint; 2] {
+        [
+            LinePoint {
+                pos: Self::BOW_POS,
+                color: 0xFFFFFFC0,
+            },
+            LinePoint {
+                pos: target,
+                color: 0xFFFFFF00,
+            },
+        ]
+    }
+
+    /// Outlines of the camera bounds and of the lantern orbit
+    fn add_debug_lines(&mut self) {
+        let bounds = self.layers.cameras[self.camera].bounds.unwrap();
+        let lines = self.layers.lines(self.lines).unwrap();
+        let point = |pos| LinePoint {
+            pos,
+            color: 0xFFFF00A0,
+        };
+
+        let corners = [
+            bounds.min,
+            Vec2::new(bounds.max.x, bounds.min.y),
+            bounds.max,
+            Vec2::new(bounds.min.x, bounds.max.y),
+        ]
+        .map(point);
+        let style = LineStyle {
+            join: LineJoin::Bevel,
+            ..LineStyle::new(0.04)
+        };
+        self.debug_lines.push(lines.add_polygon(&corners, &style));
+
+        let orbit: Vec<LinePoint> = (0..32)
+            .map(|i| {
+                let angle = i as f32 / 32. * std::f32::consts::TAU;
+                point(Self::LANTERN_POS + Vec2::new(Self::LANTERN_RADIUS, 0.).rotate(angle))
+            })
+            .collect();
+        let style = LineStyle {
+            join: LineJoin::Round,
+            dash: Vec2::new(0.05, 0.05),
+            ..LineStyle::new(0.01)
+        };
+        self.debug_lines.push(lines.add_polygon(&orbit, &style));
+    }
+
+    fn set_aim_line(&mut self) {
+        self.layers.lines(self.lines).unwrap().set_polyline(
+            self.aim_line,
+            &Self::aim_points(self.aim_target),
+            &Self::aim_style(self.aim_cap),
+        );
+    }
+
+    /// Tiles the texture instead of stretching it
+    fn tiles(texture: TextureAtlasView) -> QuadInstance {
+        QuadInstance::new_tiled(Vec2::zero(), Vec2::new(12., 12.), 2., texture)
+    }
+
+    /// Places the arrow at the edge of the screen, towards the embers
+    fn update_arrow(&mut self) {
+        let clip = self.layers.cameras[self.camera].project(Self::EMBERS_POS);
+        let edge = clip.x.abs().max(clip.y.abs());
+
+        let mut arrow = QuadInstance::new_tex(Vec2::zero(), 0.1, TextureAtlas::view_arrow());
+        if edge > 1. {
+            arrow.pos = self.layers.cameras[self.ui_camera].transform(clip / edge * 0.9);
+            arrow.angle = -f32::atan2(clip.y, clip.x);
+        } else {
+            arrow.disable();
+        }
+
+        let ui = self.layers.quads(self.ui).unwrap();
+        *ui.buffer.get_mut(self.arrow) = arrow;
+    }
+
+    fn add_lantern(nodes: &mut SceneGraph, quads: LayerId, ligths: LayerId) -> Lantern {
+        let root = nodes.add_node(None, Transform::new(Self::LANTERN_POS));
+        let lamp = nodes.add_node(
+            Some(root),
+            Transform::new(Vec2::new(Self::LANTERN_RADIUS, 0.)),
+        );
+        // Glows in the dark and ignores the shadows
+        let mut quad = QuadInstance::new_tex(Vec2::zero(), 0.08, TextureAtlas::view_block_sq3());
+        quad.set_flag(QuadInstance::SELF_ILLUMINATED, true);
+        quad.set_flag(QuadInstance::RECEIVES_SHADOWS, false);
+        nodes.add_quad(lamp, quads, quad);
+        let (r, g, b) = Self::LANTERN_COLOR;
+        nodes.add_ligth(lamp, ligths, Vec2::zero(), LigthUniform::color(r, g, b));
+        Lantern { root, lamp }
+    }
+
+    /// Arc behind the lamp, which turns clockwise by `angle` radians
+    fn lantern_trail(angle: f32) -> ShapeInstance {
+        let radius = Self::LANTERN_RADIUS + 0.015;
+        ShapeInstance::arc(Self::LANTERN_POS, radius, 0.03, -angle, 1.2, 0xFFBE6E60)
+    }
+
+    /// Glowing sparks rising from a small line
+    fn embers() -> EmitterDescriptor {
+        EmitterDescriptor {
+            shape: EmitterShape::Line(Vec2::new(-0.06, 0.), Vec2::new(0.06, 0.)),
+            speed: 0.1..0.3,
+            direction: std::f32::consts::FRAC_PI_2,
+            spread: 0.4,
+            gravity: Vec2::new(0., 0.15),
+            drag: 0.8,
+            size: Curve::linear(0.025, 0.),
+            color: ColorCurve::new(&[(0., 0xFFE0A0FF), (0.6, 0xFF8030C0), (1., 0xC0301000)]),
+            glow: Some(ParticleGlow {
+                color: LigthUniform::color(255, 150, 60),
+                radius: 0.12,
+            }),
+            ..EmitterDescriptor::new(60., 0.8..1.6)
+        }
+    }
+}
+
+impl Scene for Demo {
+    fn new(ctx: &WgpuContext) -> Self {
+        let mut layers = LayerStack::new();
+        let mut nodes = SceneGraph::new();
+
+        let mut camera = Camera::new(ctx);
+        // Keeps the view over the background
+        camera.bounds = Some(CameraBounds {
+            min: Vec2::new(-6., -6.),
+            max: Vec2::new(6., 6.),
+        });
+        // The background is far behind the game
+        let background_parallax = camera.add_parallax(
+            ctx,
+            Parallax {
+                factor: Vec2::new(0.6, 0.6),
+                scale: 0.5,
+            },
+        );
+        let camera = layers.add_camera(camera);
+        let ui_camera = layers.add_camera(Camera::new(ctx));
+        let background_camera = LayerCamera {
+            camera,
+            parallax: Some(background_parallax),
+        };
+
+        let background_ligths = layers.push(
+            Layer::new(
+                "background_ligths",
+                LayerContent::Ligths(LigthLayer::new(ctx)),
+            )
+            .with_camera(background_camera),
+        );
+        let mut background = QuadLayer::new(ctx);
+        background.enable_culling(1.);
+        let background = layers.push(
+            Layer::new("background", LayerContent::Quads(Box::new(background)))
+                .with_camera(background_camera),
+        );
+        let ligths = layers.push(Layer::new(
+            "ligths",
+            LayerContent::Ligths(LigthLayer::new(ctx)),
+        ));
+        let particles = layers.push(
+            Layer::new(
+                "particles",
+                LayerContent::Particles(ParticleLayer::new(ctx)),
+            )
+            .with_blend(BlendMode::Additive),
+        );
+        let mut players = QuadLayer::new(ctx);
+        // Lower quads in front
+        players.order = QuadOrder::YSort;
+        let players = layers.push(Layer::new(
+            "players",
+            LayerContent::Quads(Box::new(players)),
+        ));
+        // Layers can be added around the ones that already exist
+        let shapes = layers.insert_below(
+            players,
+            Layer::new("shapes", LayerContent::Shapes(ShapeLayer::new(ctx))),
+        );
+        let lines = layers.insert_above(
+            players,
+            Layer::new("lines", LayerContent::Lines(LineLayer::new(ctx))),
+        );
+        let ui = layers.push(
+            Layer::new("ui", LayerContent::Quads(Box::new(QuadLayer::new(ctx))))
+                .unlit()
+                .with_camera(LayerCamera::new(ui_camera)),
+        );
+
+        // Looked up by name, like the sprites of a level file
+        let tile_sprite = Sprite::from_name("triangles").unwrap();
+        let tiles = layers
+            .quads(background)
+            .unwrap()
+            .buffer
+            .push(Self::tiles(TextureAtlas::get("triangles").unwrap()));
+
+        let shape_layer = layers.shapes(shapes).unwrap();
+        // Rug and selection ring under the bow
+        shape_layer.buffer.push(ShapeInstance::rounded_rect(
+            Self::BOW_POS,
+            Vec2::new(0.6, 0.5),
+            0.08,
+            0x5A3C28FF,
+        ));
+        shape_layer.buffer.push(
+            ShapeInstance::ring(Self::BOW_POS, 0.2, 0.03, 0xC8C8C8FF)
+                .with_outline(0.008, 0x646464FF),
+        );
+        // Pit of the embers
+        shape_layer.buffer.push(
+            ShapeInstance::circle(Self::EMBERS_POS, 0.1, 0x2A2420FF)
+                .with_outline(0.015, 0x6E6458FF),
+        );
+        // Stand of the lantern and the trail of its lamp, see `update`
+        shape_layer.buffer.push(ShapeInstance::ellipse(
+            Self::LANTERN_POS,
+            Vec2::new(0.12, 0.06),
+            0x3C3C46FF,
+        ));
+        let lantern_trail = shape_layer.buffer.push(Self::lantern_trail(0.));
+
+        let mut bow = AnimatedSprite::new(
+            layers.quads(players).unwrap(),
+            Self::BOW_POS,
+            0.3,
+            TextureAtlas::clip_bow_charge(),
+        );
+        // Rotate around the grip
+        bow.set_pivot(layers.quads(players).unwrap(), Vec2::new(0.4, 0.));
+        // Above the rest of the players
+        layers.quads(players).unwrap().set_z(bow.quad(), 1.);
+
+        let aim_line = layers.lines(lines).unwrap().add_polyline(
+            &Self::aim_points(Vec2::zero()),
+            &Self::aim_style(LineCap::Round),
+        );
+
+        let embers = ParticleEmitter::new(Self::EMBERS_POS, Self::embers(), 1);
+        layers.particles(particles).unwrap().add_emitter(embers);
+
+        // Only shines on the background, moving with it
+        layers.ligths(background_ligths).unwrap().add_ligth(
+            ctx,
+            Vec2::new(2., 1.),
+            LigthUniform::color(60, 60, 90),
+        );
+
+        let ligth = layers.ligths(ligths).unwrap().add_ligth(
+            ctx,
+            Vec2::zero(),
+            LigthUniform::color(130, 130, 130),
+        );
+
+        let arrow = layers
+            .quads(ui)
+            .unwrap()
+            .buffer
+            .push(QuadInstance::new_color(Vec2::zero(), Vec2::zero(), 0));
+
+        let lantern = Self::add_lantern(&mut nodes, players, ligths);
+
+        Self {
+            layers,
+            nodes,
+            camera,
+            ui_camera,
+            background,
+            ligths,
+            players,
+            shapes,
+            lines,
+            ui,
+
+            ligth,
+            tiles,
+            tile_sprite,
+            bow,
+            aim_line,
+            aim_target: Vec2::zero(),
+            aim_cap: LineCap::Round,
+            debug_lines: Vec::new(),
+            arrow,
+            lantern,
+            lantern_trail,
+            time: 0.,
+        }
+    }
+
+    fn update(&mut self, delta_time: f32) {
+        self.bow
+            .update(self.layers.quads(self.players).unwrap(), delta_time);
+        self.layers.update(delta_time);
+        self.update_arrow();
+
+        // Children follow the rotation of the root
+        self.time += delta_time;
+        self.nodes.set_angle(self.lantern.root, self.time);
+        let shapes = self.layers.shapes(self.shapes).unwrap();
+        *shapes.buffer.get_mut(self.lantern_trail) = Self::lantern_trail(self.time);
+        let (r, g, b) = Self::LANTERN_COLOR;
+        let flicker = 1. + 0.15 * (self.time * 13.).sin() * (self.time * 7.).sin();
+        self.nodes.set_ligth_color(
+            self.lantern.lamp,
+            0,
+            LigthUniform::scale_color(LigthUniform::color(r, g, b), flicker),
+        );
+    }
+
+    fn resize(&mut self, size: Vec2) {
+        self.layers.resize(size);
+    }
+
+    fn prepare(&mut self, ctx: &WgpuContext) -> bool {
+        self.nodes.sync(ctx, &mut self.layers);
+        self.layers.prepare()
+    }
+
+    fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, shaders: &'a Shaders) {
+        self.layers.draw(pass, shaders);
+    }
+}
+
+impl InputEventHandler<()> for Demo {
+    /// See the keys in `Demo`
+    fn typed_text(&mut self, text: &str, _: &mut ()) {
+        for key in text.chars() {
+            let camera = &mut self.layers.cameras[self.camera];
+            let step = 0.2 / camera.zoom();
+            match key {
+                'w' => camera.set_pos(camera.pos() + Vec2::new(0., step)),
+                'a' => camera.set_pos(camera.pos() - Vec2::new(step, 0.)),
+                's' => camera.set_pos(camera.pos() - Vec2::new(0., step)),
+                'd' => camera.set_pos(camera.pos() + Vec2::new(step, 0.)),
+                '+' => camera.set_zoom(camera.zoom() * 1.25),
+                '-' => camera.set_zoom(camera.zoom() / 1.25),
+                'q' => camera.set_rotation(camera.rotation() + 0.1),
+                'e' => camera.set_rotation(camera.rotation() - 0.1),
+                'x' => camera.shake.add_trauma(0.5),
+                'b' => {
+                    let background = self.layers.layer_mut(self.background);
+                    background.visible = !background.visible;
+                }
+                'l' => {
+                    let visible = self.nodes.is_visible(self.lantern.root);
+                    self.nodes.set_visible(self.lantern.root, !visible);
+                }
+                'r' => {
+                    self.nodes.remove_node(self.lantern.root);
+                    self.lantern = Self::add_lantern(&mut self.nodes, self.players, self.ligths);
+                }
+                'o' => {
+                    let players = self.layers.quads(self.players).unwrap();
+                    players.order = match players.order {
+                        QuadOrder::YSort => QuadOrder::Z,
+                        _ => QuadOrder::YSort,
+                    };
+                }
+                'c' => {
+                    self.aim_cap = match self.aim_cap {
+                        LineCap::Butt => LineCap::Square,
+                        LineCap::Square => LineCap::Round,
+                        LineCap::Round => LineCap::Butt,
+                    };
+                    self.set_aim_line();
+                }
+                'g' => {
+                    if self.debug_lines.is_empty() {
+                        self.add_debug_lines();
+                    } else {
+                        let lines = self.layers.lines(self.lines).unwrap();
+                        for line in self.debug_lines.drain(..) {
+                            lines.remove(line);
+                        }
+                    }
+                }
+                'f' => {
+                    let mut clip = self.bow.clip();
+                    clip.mode = match clip.mode {
+                        AnimationMode::PingPong => AnimationMode::Once,
+                        AnimationMode::Once => AnimationMode::Loop,
+                        AnimationMode::Loop => AnimationMode::PingPong,
+                    };
+                    self.bow
+                        .play(self.layers.quads(self.players).unwrap(), clip);
+                }
+                't' => {
+                    let next = Sprite::from_id(self.tile_sprite.id() + 1);
+                    self.tile_sprite = next.unwrap_or(Sprite::ALL[0]);
+                    let background = self.layers.quads(self.background).unwrap();
+                    *background.buffer.get_mut(self.tiles) = Self::tiles(self.tile_sprite.view());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn mouse_moved(&mut self, mouse: &Mouse, _: &mut ()) {
+        let pos = mouse.transform(&self.layers.cameras[self.camera]).pos;
+
+        let ligth = self
+            .layers
+            .ligths(self.ligths)
+            .unwrap()
+            .get_ligth_mut(self.ligth);
+        ligth.data.pos = pos;
+        ligth.needs_update = true;
+
+        // Aim the bow at the mouse, facing left instead of turning upside down
+        let aim = pos - Self::BOW_POS;
+        let bow = self
+            .layers
+            .quads(self.players)
+            .unwrap()
+            .buffer
+            .get_mut(self.bow.quad());
+        bow.set_flip(aim.x < 0., false);
+        let angle = f32::atan2(aim.y, aim.x.abs());
+        bow.angle = if aim.x < 0. { angle } else { -angle };
+
+        self.aim_target = pos;
+        self.set_aim_line();
+    }
+}
@@ -2,45 +2,83 @@ mod ui;
 
 use self::ui::LobbyUI;
 
-use super::*;
+use super::Scene;
+use crate::camera::*;
 use crate::input::*;
+use crate::layers::*;
+use crate::ligth_pipeline::*;
 use crate::math::*;
 use crate::objects::*;
+use crate::scene_graph::*;
+use crate::shaders::*;
 use crate::texture_atlas::*;
+use crate::wgpu_components::*;
 
 pub struct Lobby {
-    game_layers: GameLayers,
-    frame_layers: FrameLayers,
+    layers: LayerStack,
+    nodes: SceneGraph,
+    camera: usize,
+    ui_camera: usize,
+    ligths: LayerId,
+    ui_layer: LayerId,
     ui: LobbyUI,
 
     block: BlockSq2,
     ligth: usize,
 }
 
-impl Lobby {
-    pub fn new(ctx: &WgpuContext) -> Self {
-        let mut game_layers = GameLayers::new(ctx);
-        let mut frame_layers = FrameLayers::new(ctx);
+impl Scene for Lobby {
+    fn new(ctx: &WgpuContext) -> Self {
+        let mut layers = LayerStack::new();
+        let mut nodes = SceneGraph::new();
 
-        game_layers.background.buffer.push(QuadInstance::new_tex(
-            Vec2::zero(),
-            4.,
-            TextureAtlas::view_triangles(),
+        let camera = layers.add_camera(Camera::new(ctx));
+        let ui_camera = layers.add_camera(Camera::new(ctx));
+
+        let ligths = layers.push(Layer::new(
+            "ligths",
+            LayerContent::Ligths(LigthLayer::new(ctx)),
+        ));
+        let background = layers.push(Layer::new(
+            "background",
+            LayerContent::Quads(Box::new(QuadLayer::new(ctx))),
         ));
+        let blocks = layers
+            .push(Layer::new("blocks", LayerContent::Quads(Box::new(QuadLayer::new(ctx)))).unlit());
+        let ui_layer = layers.push(
+            Layer::new("ui", LayerContent::Quads(Box::new(QuadLayer::new(ctx))))
+                .unlit()
+                .with_camera(LayerCamera::new(ui_camera)),
+        );
 
-        let ligth =
-            game_layers
-                .ligths
-                .add_ligth(ctx, Vec2::zero(), LigthUniform::color(130, 130, 130));
+        layers
+            .quads(background)
+            .unwrap()
+            .buffer
+            .push(QuadInstance::new_tex(
+                Vec2::zero(),
+                4.,
+                TextureAtlas::view_triangles(),
+            ));
+
+        let ligth = layers.ligths(ligths).unwrap().add_ligth(
+            ctx,
+            Vec2::zero(),
+            LigthUniform::color(130, 130, 130),
+        );
 
-        BlockSq3::new(&mut game_layers, Vec2::new(0., 0.4));
-        let block = BlockSq2::new(&mut game_layers, Vec2::zero());
+        BlockSq3::new(&mut nodes, blocks, ligths, Vec2::new(0., 0.4));
+        let block = BlockSq2::new(&mut nodes, blocks, ligths, Vec2::zero());
 
-        let ui = LobbyUI::new(&mut frame_layers.ui);
+        let ui = LobbyUI::new(layers.quads(ui_layer).unwrap());
 
         Self {
-            game_layers,
-            frame_layers,
+            layers,
+            nodes,
+            camera,
+            ui_camera,
+            ligths,
+            ui_layer,
             ui,
 
             block,
@@ -48,33 +86,44 @@ impl Lobby {
         }
     }
 
-    pub fn resize(&mut self, size: Vec2) {
-        self.game_layers.camera.resize(size);
-        self.frame_layers.camera.resize(size);
+    fn update(&mut self, delta_time: f32) {
+        self.layers.update(delta_time);
     }
 
-    pub fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, shaders: &'a Shaders) {
-        self.game_layers.draw_game(pass, shaders);
-        self.frame_layers.draw_game(pass, shaders);
+    fn resize(&mut self, size: Vec2) {
+        self.layers.resize(size);
+    }
+
+    fn prepare(&mut self, ctx: &WgpuContext) -> bool {
+        self.nodes.sync(ctx, &mut self.layers);
+        self.layers.prepare()
+    }
+
+    fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, shaders: &'a Shaders) {
+        self.layers.draw(pass, shaders);
     }
 }
 
 impl InputEventHandler<()> for Lobby {
     fn update(&mut self, input: &InputRef, _: &mut ()) {
-        input.propagate_transformed_events(
-            &self.frame_layers.camera,
-            &mut self.ui,
-            &mut self.frame_layers.ui,
-        );
+        let (camera, layer) = self
+            .layers
+            .camera_and_quads(self.ui_camera, self.ui_layer)
+            .unwrap();
+        input.propagate_transformed_events(camera, &mut self.ui, layer);
     }
 
     fn mouse_moved(&mut self, mouse: &Mouse, _: &mut ()) {
-        let game_mouse = mouse.transform(&self.game_layers.camera);
+        let game_mouse = mouse.transform(&self.layers.cameras[self.camera]);
         let pos = game_mouse.pos;
 
-        self.block.set_pos(&mut self.game_layers, pos);
+        self.block.set_pos(&mut self.nodes, pos);
 
-        let ligth = self.game_layers.ligths.get_ligth_mut(self.ligth);
+        let ligth = self
+            .layers
+            .ligths(self.ligths)
+            .unwrap()
+            .get_ligth_mut(self.ligth);
         ligth.data.pos = -pos;
         ligth.needs_update = true;
     }
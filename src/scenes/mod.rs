@@ -1,74 +1,26 @@
+mod demo;
 mod lobby;
 
+pub use demo::*;
 pub use lobby::*;
 
-use crate::camera::*;
-use crate::layers::*;
-use crate::ligth_pipeline::*;
-use crate::shaders::*;
-use crate::wgpu_components::*;
+use crate::input::InputEventHandler;
+use crate::ligth_pipeline::LigthRenderPass;
+use crate::math::Vec2;
+use crate::shaders::Shaders;
+use crate::wgpu_components::WgpuContext;
 
-pub struct GameLayers {
-    pub camera: Camera,
-    pub ligths: LigthLayer,
-    pub background: QuadLayer,
-    pub bottom_particles: QuadLayer,
-    pub players: QuadLayer,
-    pub blocks: QuadLayer,
-    pub top_particles: QuadLayer,
-}
-
-pub struct FrameLayers {
-    pub camera: Camera,
-    pub frame: QuadLayer,
-    pub ui: QuadLayer,
-}
-
-impl GameLayers {
-    pub fn new(ctx: &WgpuContext) -> Self {
-        GameLayers {
-            camera: Camera::new(ctx),
-            ligths: LigthLayer::new(ctx),
-            background: QuadLayer::new(ctx),
-            bottom_particles: QuadLayer::new(ctx),
-            players: QuadLayer::new(ctx),
-            blocks: QuadLayer::new(ctx),
-            top_particles: QuadLayer::new(ctx),
-        }
-    }
-
-    pub fn draw_game<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, shaders: &'a Shaders) {
-        self.camera.bind(pass);
+/// Scene drawn by the `SceneManager`
+pub trait Scene: InputEventHandler<()> {
+    fn new(ctx: &WgpuContext) -> Self;
 
-        shaders.ligth.bind(pass);
-        shaders.quad.bind_ligth(pass);
-
-        self.ligths.draw(pass);
-        self.background.draw(pass);
-        self.bottom_particles.draw(pass);
-        self.players.draw(pass);
-
-        shaders.quad.bind(pass);
-
-        self.blocks.draw(pass);
-        self.top_particles.draw(pass);
-    }
-}
+    /// Advances the scene `delta_time` seconds
+    fn update(&mut self, delta_time: f32);
 
-impl FrameLayers {
-    pub fn new(ctx: &WgpuContext) -> Self {
-        FrameLayers {
-            camera: Camera::new(ctx),
-            frame: QuadLayer::new(ctx),
-            ui: QuadLayer::new(ctx),
-        }
-    }
+    fn resize(&mut self, size: Vec2);
 
-    pub fn draw_game<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, shaders: &'a Shaders) {
-        self.camera.bind(pass);
+    /// Returns whether the frame needs the unshadowed ligth pass
+    fn prepare(&mut self, ctx: &WgpuContext) -> bool;
 
-        shaders.quad.bind(pass);
-        self.frame.draw(pass);
-        self.ui.draw(pass);
-    }
+    fn draw<'a>(&'a mut self, pass: &mut LigthRenderPass<'a>, shaders: &'a Shaders);
 }
@@ -1,24 +1,77 @@
+mod glow;
 mod ligth;
+mod line;
 mod quad;
+mod shape;
 
+pub use glow::*;
 pub use ligth::*;
+pub use line::*;
 pub use quad::*;
+pub use shape::*;
 
 use crate::{error::ErrResult, ligth_pipeline::LigthTextures, WgpuContext};
 
 pub struct Shaders {
     pub quad: QuadShader,
     pub ligth: LigthShader,
+    pub shape: ShapeShader,
+    pub line: LineShader,
+    pub glow: GlowShader,
 }
 impl Shaders {
     pub fn new(ctx: &WgpuContext, textures: &LigthTextures) -> ErrResult<Self> {
         Ok(Shaders {
             ligth: LigthShader::new(ctx, textures),
             quad: QuadShader::new(ctx, textures)?,
+            shape: ShapeShader::new(ctx, textures),
+            line: LineShader::new(ctx, textures),
+            glow: GlowShader::new(ctx, textures),
         })
     }
     pub fn resize(&mut self, ctx: &WgpuContext, textures: &LigthTextures) {
         self.ligth.resize(ctx, textures);
         self.quad.resize(ctx, textures);
+        self.shape.resize(ctx, textures);
+        self.line.resize(ctx, textures);
+        self.glow.resize(ctx, textures);
+    }
+}
+
+/// Whether a layer is shaded by the ligth pass
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Lighting {
+    Lit,
+    Unlit,
+}
+
+/// How a layer is composed over the ones below it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Premultiplied alpha
+    Alpha = 0,
+    /// Adds the premultiplied color, for glows and fire
+    Additive = 1,
+}
+
+impl BlendMode {
+    pub const ALL: [BlendMode; 2] = [BlendMode::Alpha, BlendMode::Additive];
+
+    pub fn state(self) -> wgpu::BlendState {
+        match self {
+            BlendMode::Alpha => wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+            BlendMode::Additive => wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Zero,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            },
+        }
     }
 }
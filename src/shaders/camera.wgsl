@@ -0,0 +1,19 @@
+struct CameraUniform {
+    pos: vec2<f32>,
+    // Scale from the world to clip space, including the aspect ratio
+    size: vec2<f32>,
+    // Cosine and sine of the counterclockwise rotation of the camera
+    rotation: vec2<f32>,
+}
+
+@group(1) @binding(0)
+var<uniform> camera: CameraUniform;
+
+// World position to clip space, inverse of `Camera::transform`
+fn camera_transform(world: vec2<f32>) -> vec2<f32> {
+    let d = world - camera.pos;
+    let c = camera.rotation.x;
+    let s = camera.rotation.y;
+    return vec2(c * d.x + s * d.y, c * d.y - s * d.x) * camera.size;
+}
+
@@ -0,0 +1,91 @@
+use crate::ligth_pipeline::*;
+use crate::math::*;
+use crate::wgpu_components::*;
+
+/// Additive ligth drawn in the ligth pass, not occluded by the shadows
+pub struct GlowShader {
+    shader: Shader,
+    unshadowed: Shader,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GlowInstance {
+    pub pos: Vec2,
+    /// The ligth fades to zero at this distance
+    pub radius: f32,
+    /// Packed with `LigthUniform::color`
+    pub color: u32,
+    /// Multiplies the color
+    pub intensity: f32,
+}
+
+impl GlowInstance {
+    const ATTRIBS: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+        0 => Float32x2, // pos
+        1 => Float32,   // radius
+        2 => Uint32,    // color
+        3 => Float32,   // intensity
+    ];
+
+    pub fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<GlowInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+impl GlowShader {
+    pub fn new(ctx: &WgpuContext, textures: &LigthTextures) -> Self {
+        let new_shader = |depth_stencil| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(include_str!("camera.wgsl"), include_str!("glow.wgsl")).into(),
+                    textures: &[&textures.normal],
+                    texture_dimensions: &[wgpu::TextureViewDimension::D2],
+                    uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                    vertex_layout: GlowInstance::desc(),
+                    output_format: wgpu::TextureFormat::Rgb10a2Unorm,
+                    blend: wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One,
+                            dst_factor: wgpu::BlendFactor::One,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent::REPLACE,
+                    },
+                    depth_stencil,
+                    ..Default::default()
+                },
+            )
+        };
+
+        // Shares the depth buffer of the shadows without testing against it
+        let shader = new_shader(Some(wgpu::DepthStencilState {
+            format: wgpu::TextureFormat::Depth32Float,
+            depth_write_enabled: false,
+            depth_compare: wgpu::CompareFunction::Always,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }));
+        let unshadowed = new_shader(None);
+
+        Self { shader, unshadowed }
+    }
+
+    pub fn resize(&mut self, ctx: &WgpuContext, textures: &LigthTextures) {
+        self.shader.update_textures(ctx, &[&textures.normal]);
+        self.unshadowed.update_textures(ctx, &[&textures.normal]);
+    }
+
+    /// Binds the ligth and the unshadowed passes
+    pub fn bind<'a>(&'a self, pass: &mut LigthRenderPass<'a>) {
+        self.shader.bind(&mut pass.ligth);
+        if let Some(unshadowed) = &mut pass.unshadowed {
+            self.unshadowed.bind(unshadowed);
+        }
+    }
+}
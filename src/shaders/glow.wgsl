@@ -0,0 +1,65 @@
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+    @location(0) pos: vec2<f32>,
+    @location(1) radius: f32,
+    @location(2) color: u32,
+    @location(3) intensity: f32,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_pos: vec4<f32>,
+    @location(0) pos: vec2<f32>,
+    @location(1) screen_pos: vec2<f32>,
+    @location(2) @interpolate(flat) center: vec2<f32>,
+    @location(3) @interpolate(flat) radius: f32,
+    @location(4) @interpolate(flat) color: vec3<f32>,
+};
+
+fn quad_mesh(i: u32) -> vec2<f32> {
+    return vec2(f32((i & 1u) * 2u), f32(i & 2u)) - 1.;
+}
+
+// Same encoding as `LigthUniform::color`
+fn decode_u32_color(color: u32) -> vec3<f32> {
+    let b = color & 0x3FFu;
+    let g = (color >> 10u) & 0x3FFu;
+    let r = color >> 20u;
+    let col = vec3(f32(r), f32(g), f32(b)) * 4. / 255.;
+    return col * col;
+}
+
+@vertex
+fn vs_main(model: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.pos = model.pos + quad_mesh(model.vertex_index) * model.radius;
+    out.center = model.pos;
+    out.radius = model.radius;
+    out.color = decode_u32_color(model.color) * model.intensity;
+
+    out.screen_pos = camera_transform(out.pos);
+    out.clip_pos = vec4<f32>(out.screen_pos, 0., 1.);
+    return out;
+}
+
+@group(0) @binding(0)
+var tex_sampler: sampler;
+
+@group(0) @binding(1)
+var normal_tex: texture_2d<f32>;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let normal_color = textureSample(normal_tex, tex_sampler, in.screen_pos * vec2(0.5, -0.5) + 0.5).rgb;
+
+    // Reaches zero at the radius, so the quad can be small
+    let falloff = clamp(1. - length(in.pos - in.center) / in.radius, 0., 1.);
+    let dist_attenuation = falloff * falloff;
+
+    let dist_vec = vec3(in.center, in.radius * 0.25) - vec3(in.pos, 0.);
+    let normal = normalize(normal_color * 2. - 1.);
+    let angle_attenuation = max(0., dot(normalize(dist_vec), normal));
+
+    let final_color = angle_attenuation * dist_attenuation * in.color;
+    return vec4((final_color / 4.), 1.);
+}
@@ -4,6 +4,7 @@ use crate::wgpu_components::*;
 
 pub struct LigthShader {
     shader: Shader,
+    unshadowed: Shader,
 }
 
 #[repr(C)]
@@ -11,12 +12,25 @@ pub struct LigthShader {
 pub struct ShadowInstance {
     pub a: Vec2,
     pub b: Vec2,
+    /// Height of the caster. The shadow ends where the ligth passes over it,
+    /// casters as tall as the ligth have infinite shadows
+    pub height: f32,
+    /// Bits of the shadow layers of the caster, see `LigthUniform::mask`
+    pub layers: u32,
 }
 
 impl ShadowInstance {
-    const ATTRIBS: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![
+    pub const DEFAULT_LAYERS: u32 = 1;
+
+    /// Casts infinite shadows from any ligth
+    #[allow(unused)]
+    pub const INFINITE_HEIGHT: f32 = f32::INFINITY;
+
+    const ATTRIBS: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
         0 => Float32x2, // pos a
         1 => Float32x2, // pos b
+        2 => Float32,   // height
+        3 => Uint32,    // layers
     ];
 
     pub fn desc() -> wgpu::VertexBufferLayout<'static> {
@@ -34,56 +48,101 @@ pub struct LigthUniform {
     pub pos: Vec2,
     pub z_index: f32,
     pub color: u32,
+    /// Height above the ground, limits the length of the shadows
+    pub height: f32,
+    /// Shadow layers that block the ligth, the other casters don't shadow it
+    pub mask: u32,
+    padding: [f32; 2],
 }
 
 impl LigthUniform {
+    pub const DEFAULT_HEIGHT: f32 = 0.5;
+    /// Blocked by all the shadow layers
+    pub const ALL_SHADOWS: u32 = u32::MAX;
+
+    pub fn new(pos: Vec2, color: u32) -> Self {
+        Self {
+            pos,
+            // Assigned on draw
+            z_index: 0.,
+            color,
+            height: Self::DEFAULT_HEIGHT,
+            mask: Self::ALL_SHADOWS,
+            padding: [0.; 2],
+        }
+    }
+
     pub fn color(r: u16, g: u16, b: u16) -> u32 {
         let r = (r & 0x3FF) as u32;
         let g = (g & 0x3FF) as u32;
         let b = (b & 0x3FF) as u32;
         (r << 20) | (g << 10) | b
     }
+
+    /// Multiplies the intensity of a packed color, saturating.
+    /// The channels are squared by the shader, so they are scaled by the square root
+    pub fn scale_color(color: u32, factor: f32) -> u32 {
+        let scale = factor.max(0.).sqrt();
+        let channel = |shift: u32| {
+            let value = ((color >> shift) & 0x3FF) as f32 * scale;
+            value.round().min(0x3FF as f32) as u16
+        };
+        Self::color(channel(20), channel(10), channel(0))
+    }
 }
 
 impl LigthShader {
     pub fn new(ctx: &WgpuContext, textures: &LigthTextures) -> Self {
-        let shader = Shader::new(
-            ctx,
-            ShaderDescriptor {
-                src: include_str!("ligth.wgsl").into(),
-                textures: &[&textures.normal],
-                uniforms: &[
-                    &Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX),
-                    &Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX),
-                    &Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX_FRAGMENT),
-                ],
-                vertex_layout: ShadowInstance::desc(),
-                output_format: wgpu::TextureFormat::Rgb10a2Unorm,
-                blend: wgpu::BlendState {
-                    color: wgpu::BlendComponent {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::One,
-                        operation: wgpu::BlendOperation::Add,
+        let new_shader = |depth_stencil| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(include_str!("camera.wgsl"), include_str!("ligth.wgsl")).into(),
+                    textures: &[&textures.normal],
+                    texture_dimensions: &[wgpu::TextureViewDimension::D2],
+                    uniforms: &[
+                        &Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX),
+                        &Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX),
+                        &Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX_FRAGMENT),
+                    ],
+                    vertex_layout: ShadowInstance::desc(),
+                    output_format: wgpu::TextureFormat::Rgb10a2Unorm,
+                    blend: wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One,
+                            dst_factor: wgpu::BlendFactor::One,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent::REPLACE,
                     },
-                    alpha: wgpu::BlendComponent::REPLACE,
+                    depth_stencil,
+                    ..Default::default()
                 },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Less,
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
-            },
-        );
-        Self { shader }
+            )
+        };
+
+        let shader = new_shader(Some(wgpu::DepthStencilState {
+            format: wgpu::TextureFormat::Depth32Float,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::Less,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }));
+        // Only the ligth instances are drawn in the unshadowed pass
+        let unshadowed = new_shader(None);
+
+        Self { shader, unshadowed }
     }
 
     pub fn resize(&mut self, ctx: &WgpuContext, textures: &LigthTextures) {
-        self.shader.update_textures(ctx, &[&textures.normal])
+        self.shader.update_textures(ctx, &[&textures.normal]);
+        self.unshadowed.update_textures(ctx, &[&textures.normal]);
     }
 
     pub fn bind<'a>(&'a self, pass: &mut LigthRenderPass<'a>) {
         self.shader.bind(&mut pass.ligth);
+        if let Some(unshadowed) = &mut pass.unshadowed {
+            self.unshadowed.bind(unshadowed);
+        }
     }
 }
@@ -1,6 +1,9 @@
 struct LigthUniform {
     pos: vec3<f32>,
     color: u32,
+    height: f32,
+    // Shadow layers that block the ligth
+    mask: u32,
 }
 
 @group(3) @binding(0)
@@ -9,19 +12,13 @@ var<uniform> ligth: LigthUniform;
 @group(2) @binding(0)
 var<uniform> ligth_index: u32;
 
-struct CameraUniform {
-    pos: vec2<f32>,
-    size: vec2<f32>,
-}
-
-@group(1) @binding(0)
-var<uniform> camera: CameraUniform;
-
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
     @builtin(instance_index) instance_index: u32,
     @location(0) a: vec2<f32>,
     @location(1) b: vec2<f32>,
+    @location(2) height: f32,
+    @location(3) layers: u32,
 };
 
 struct VertexOutput {
@@ -35,6 +32,16 @@ fn quad_mesh(i: u32) -> vec2<f32> {
     return vec2(f32((i & 1u) * 2u), f32(i & 2u)) - 1.;
 }
 
+// Length of the shadow relative to the distance from the ligth to the caster.
+// The ray over the top of the caster reaches the ground at h / (H - h)
+fn shadow_length(height: f32) -> f32 {
+    let max_length = 100.;
+    if height >= ligth.height {
+        return max_length;
+    }
+    return min(height / (ligth.height - height), max_length);
+}
+
 @vertex
 fn vs_main(model: VertexInput) -> VertexOutput {
     var out: VertexOutput;
@@ -42,18 +49,19 @@ fn vs_main(model: VertexInput) -> VertexOutput {
     if model.instance_index == ligth_index {
         out.ligth = 1.;
         out.pos = quad_mesh(model.vertex_index) * 100.;
-    } else  {
-        let shadow_size = 100.;
-               
+    } else if (model.layers & ligth.mask) == 0u {
+        // Degenerate quad, the caster does not block this ligth
+        out.pos = model.a;
+    } else {
         out.pos = select(model.a, model.b, vec2<bool>((model.vertex_index & 1u) == 0u));
         
         if (model.vertex_index & 2u) != 0u {
-            out.pos += (out.pos - ligth.pos.xy) * shadow_size;
+            out.pos += (out.pos - ligth.pos.xy) * shadow_length(model.height);
         }
     }
 
-    out.screen_pos = out.pos * camera.size;
-    out.clip_pos = vec4<f32>(out.screen_pos + camera.pos, ligth.pos.z, 1.);
+    out.screen_pos = camera_transform(out.pos);
+    out.clip_pos = vec4<f32>(out.screen_pos, ligth.pos.z, 1.);
     return out;
 }
 
@@ -77,7 +85,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let normal_color = textureSample(normal_tex, tex_sampler, in.screen_pos * vec2(0.5, -0.5) + 0.5).rgb;
         
     if in.ligth > 0. {
-        let ligth_pos = vec3(ligth.pos.xy, 0.5);
+        let ligth_pos = vec3(ligth.pos.xy, ligth.height);
         let ligth_color = decode_u32_color();
 
         let dist_vec = ligth_pos - vec3(in.pos, 0.);
@@ -0,0 +1,134 @@
+use super::{BlendMode, Lighting};
+use crate::ligth_pipeline::*;
+use crate::math::*;
+use crate::wgpu_components::*;
+
+/// Draws the triangles of tessellated lines, see `LineLayer`
+pub struct LineShader {
+    color: [Shader; 2],
+    diffuse: [Shader; 2],
+    normal: Shader,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::NoUninit)]
+pub struct LineVertex {
+    pub pos: Vec2,
+    /// 0xRRGGBBAA
+    pub color: u32,
+    /// Distance along the line, used by the dashes
+    pub distance: f32,
+    /// Length of the dashes and the gaps between them
+    pub dash: Vec2,
+}
+
+impl LineVertex {
+    const ATTRIBS: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+        0 => Float32x2, // pos
+        1 => Unorm8x4,  // color
+        2 => Float32,   // distance
+        3 => Float32x2, // dash
+    ];
+
+    pub fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<LineVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+impl LineShader {
+    pub fn new(ctx: &WgpuContext, ligth_textures: &LigthTextures) -> Self {
+        let normal = Shader::new(
+            ctx,
+            ShaderDescriptor {
+                src: concat!(
+                    include_str!("camera.wgsl"),
+                    include_str!("line_common.wgsl"),
+                    include_str!("line_normal.wgsl")
+                )
+                .into(),
+                textures: &[],
+                texture_dimensions: &[],
+                uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                vertex_layout: LineVertex::desc(),
+                output_format: wgpu::TextureFormat::Rgb10a2Unorm,
+                blend: wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                depth_stencil: None,
+                topology: wgpu::PrimitiveTopology::TriangleList,
+            },
+        );
+
+        let diffuse = BlendMode::ALL.map(|blend| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(
+                        include_str!("camera.wgsl"),
+                        include_str!("line_common.wgsl"),
+                        include_str!("line_diffuse.wgsl")
+                    )
+                    .into(),
+                    textures: &[&ligth_textures.ligth],
+                    texture_dimensions: &[wgpu::TextureViewDimension::D2],
+                    uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                    vertex_layout: LineVertex::desc(),
+                    output_format: wgpu::TextureFormat::Bgra8Unorm,
+                    blend: blend.state(),
+                    depth_stencil: None,
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                },
+            )
+        });
+
+        let color = BlendMode::ALL.map(|blend| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(
+                        include_str!("camera.wgsl"),
+                        include_str!("line_common.wgsl"),
+                        include_str!("line.wgsl")
+                    )
+                    .into(),
+                    textures: &[],
+                    texture_dimensions: &[],
+                    uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                    vertex_layout: LineVertex::desc(),
+                    output_format: wgpu::TextureFormat::Bgra8Unorm,
+                    blend: blend.state(),
+                    depth_stencil: None,
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                },
+            )
+        });
+
+        Self {
+            normal,
+            diffuse,
+            color,
+        }
+    }
+
+    pub fn resize(&mut self, ctx: &WgpuContext, textures: &LigthTextures) {
+        for diffuse in &mut self.diffuse {
+            diffuse.update_textures(ctx, &[&textures.ligth]);
+        }
+    }
+
+    /// Unlit layers still write the flat normals of the lines in the normal pass
+    pub fn bind<'a>(
+        &'a self,
+        pass: &mut LigthRenderPass<'a>,
+        lighting: Lighting,
+        blend: BlendMode,
+    ) {
+        self.normal.bind(&mut pass.normal);
+        match lighting {
+            Lighting::Lit => self.diffuse[blend as usize].bind(&mut pass.diffuse),
+            Lighting::Unlit => self.color[blend as usize].bind(&mut pass.diffuse),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if !in_dash(in) {
+        discard;
+    }
+
+    // Premultiplied alpha
+    return vec4(in.color.rgb * in.color.a, in.color.a);
+}
@@ -0,0 +1,44 @@
+struct VertexInput {
+    @location(0) pos: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) distance: f32,
+    @location(3) dash: vec2<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) screen_pos: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    // Distance along the line
+    @location(2) distance: f32,
+    @location(3) @interpolate(flat) dash: vec2<f32>,
+};
+
+@vertex
+fn vs_main(
+    model: VertexInput,
+) -> VertexOutput {
+    var out: VertexOutput;
+
+    var pos = model.pos;
+    pos = camera_transform(pos);
+
+    out.clip_position = vec4<f32>(pos, 0., 1.);
+    out.screen_pos = pos * vec2(0.5, -0.5) + 0.5;
+    out.color = model.color.abgr;
+    out.distance = model.distance;
+    out.dash = model.dash;
+
+    return out;
+}
+
+
+
+// False in the gaps of dashed lines
+fn in_dash(in: VertexOutput) -> bool {
+    let period = in.dash.x + in.dash.y;
+    if in.dash.y <= 0. {
+        return true;
+    }
+    return in.distance - floor(in.distance / period) * period < in.dash.x;
+}
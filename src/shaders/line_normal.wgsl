@@ -0,0 +1,12 @@
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if !in_dash(in) {
+        discard;
+    }
+
+    // Lines are flat, premultiplied alpha
+    let alpha = select(0., 1., in.color.a > 0.3);
+    return vec4(vec3(0.5, 0.5, 1.) * alpha, alpha);
+}
@@ -1,3 +1,4 @@
+use super::{BlendMode, Lighting};
 use crate::error::*;
 use crate::ligth_pipeline::*;
 use crate::math::*;
@@ -5,8 +6,8 @@ use crate::texture_atlas::*;
 use crate::wgpu_components::*;
 
 pub struct QuadShader {
-    color: Shader,
-    diffuse: Shader,
+    color: [Shader; 2],
+    diffuse: [Shader; 2],
     normal: Shader,
     atlas: TextureAtlas,
 }
@@ -16,20 +17,55 @@ pub struct QuadShader {
 pub struct QuadInstance {
     pub color: u32,
     pub angle: f32,
+    /// Position of the pivot
     pub pos: Vec2,
     pub size: Vec2,
     pub tex_pos: Vec2,
     pub tex_size: Vec2,
+    /// Page of the texture atlas
+    pub page: u32,
+    /// Point of the quad placed at `pos`, relative to its center and in units of `size`.
+    /// The quad is rotated and flipped around it.
+    pub pivot: Vec2,
+    /// Combination of the `QuadInstance` flags, `QuadInstance::LIT` by default
+    pub flags: u32,
+    /// Multiplies the color and alpha (0xRRGGBBAA)
+    pub tint: u32,
+    /// Added to the color, scaled by its alpha (0xRRGGBBAA)
+    pub flash: u32,
+    /// Times that the texture is repeated along each axis
+    pub tiles: Vec2,
 }
 
 impl QuadInstance {
-    const ATTRIBS: [wgpu::VertexAttribute; 6] = wgpu::vertex_attr_array![
+    pub const FLIP_X: u32 = 1 << 0;
+    pub const FLIP_Y: u32 = 1 << 1;
+    /// Shaded by the ligths, otherwise drawn with its own color.
+    /// Unlit layers ignore the ligth flags
+    pub const RECEIVES_LIGTH: u32 = 1 << 2;
+    /// Otherwise the ligths use the normals below the quad
+    pub const WRITES_NORMALS: u32 = 1 << 3;
+    pub const RECEIVES_SHADOWS: u32 = 1 << 4;
+    /// Never darker than its own color, but still brightened by the ligths
+    pub const SELF_ILLUMINATED: u32 = 1 << 5;
+
+    pub const LIT: u32 = Self::RECEIVES_LIGTH | Self::WRITES_NORMALS | Self::RECEIVES_SHADOWS;
+
+    pub const NO_TINT: u32 = 0xFFFFFFFF;
+
+    const ATTRIBS: [wgpu::VertexAttribute; 12] = wgpu::vertex_attr_array![
         0 => Unorm8x4,  // color
         1 => Float32,   // angle
         2 => Float32x2, // pos
         3 => Float32x2, // size
         4 => Float32x2, // tex_pos
         5 => Float32x2, // tex_size
+        6 => Uint32,    // page
+        7 => Float32x2, // pivot
+        8 => Uint32,    // flags
+        9 => Unorm8x4,  // tint
+        10 => Unorm8x4, // flash
+        11 => Float32x2, // tiles
     ];
 
     pub fn desc() -> wgpu::VertexBufferLayout<'static> {
@@ -40,17 +76,57 @@ impl QuadInstance {
         }
     }
 
+    /// The trimmed texture is placed where it was in the untrimmed image,
+    /// with the pivot at the center of the untrimmed image
     pub fn new_tex(pos: Vec2, width: f32, texture: TextureAtlasView) -> Self {
+        Self::new_tex_pivot(pos, width, texture, Vec2::zero())
+    }
+
+    /// `pivot` is a point of the untrimmed image relative to its center,
+    /// from -0.5 to 0.5 with the y axis pointing up
+    pub fn new_tex_pivot(pos: Vec2, width: f32, texture: TextureAtlasView, pivot: Vec2) -> Self {
         Self {
             pos,
-            size: texture.aspect_ratio_x1() * width,
+            size: texture.aspect_ratio_x1() * width * texture.trim_scale,
             angle: 0.,
             color: 0,
             tex_pos: texture.pos,
             tex_size: texture.size,
+            page: texture.page,
+            pivot: (pivot - texture.trim_offset) / texture.trim_scale,
+            flags: Self::LIT,
+            tint: Self::NO_TINT,
+            flash: 0,
+            tiles: Vec2::new(1., 1.),
+        }
+    }
+
+    /// Fills `size` repeating the trimmed texture,
+    /// each tile has the size that `new_tex` would give it with `tile_width`
+    pub fn new_tiled(pos: Vec2, size: Vec2, tile_width: f32, texture: TextureAtlasView) -> Self {
+        let tile_size = texture.aspect_ratio_x1() * tile_width * texture.trim_scale;
+        Self {
+            pos,
+            size,
+            tiles: size / tile_size,
+            pivot: Vec2::zero(),
+            ..Self::new_tex(pos, tile_width, texture)
         }
     }
 
+    /// Replaces the texture keeping the rest of the instance, placed like `new_tex_pivot`
+    pub fn set_texture(&mut self, pos: Vec2, width: f32, texture: TextureAtlasView, pivot: Vec2) {
+        *self = Self {
+            color: self.color,
+            angle: self.angle,
+            flags: self.flags,
+            tint: self.tint,
+            flash: self.flash,
+            tiles: self.tiles,
+            ..Self::new_tex_pivot(pos, width, texture, pivot)
+        };
+    }
+
     pub fn new_color(pos: Vec2, size: Vec2, color: u32) -> Self {
         Self {
             pos,
@@ -59,8 +135,39 @@ impl QuadInstance {
             color,
             tex_pos: Vec2::new(-1., 0.),
             tex_size: Vec2::zero(),
+            page: 0,
+            pivot: Vec2::zero(),
+            flags: Self::LIT,
+            tint: Self::NO_TINT,
+            flash: 0,
+            tiles: Vec2::new(1., 1.),
+        }
+    }
+
+    /// Sets or clears `flag`
+    pub fn set_flag(&mut self, flag: u32, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
         }
     }
+
+    pub fn set_flip(&mut self, flip_x: bool, flip_y: bool) {
+        self.flags &= !(Self::FLIP_X | Self::FLIP_Y);
+        if flip_x {
+            self.flags |= Self::FLIP_X;
+        }
+        if flip_y {
+            self.flags |= Self::FLIP_Y;
+        }
+    }
+}
+
+impl Disable for QuadInstance {
+    fn disable(&mut self) {
+        self.size = Vec2::zero();
+    }
 }
 
 impl QuadShader {
@@ -70,49 +177,84 @@ impl QuadShader {
         let normal = Shader::new(
             ctx,
             ShaderDescriptor {
-                src: include_str!("quad_normal.wgsl").into(),
-                textures: &[&atlas.normal_textures[0].view],
+                src: concat!(
+                    include_str!("camera.wgsl"),
+                    include_str!("quad_common.wgsl"),
+                    include_str!("quad_normal.wgsl")
+                )
+                .into(),
+                textures: &[&atlas.normal_textures.view],
+                texture_dimensions: &[wgpu::TextureViewDimension::D2Array],
                 uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
                 vertex_layout: QuadInstance::desc(),
                 output_format: wgpu::TextureFormat::Rgb10a2Unorm,
-                blend: wgpu::BlendState::ALPHA_BLENDING,
+                blend: wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
                 depth_stencil: None,
+                ..Default::default()
             },
         );
 
-        let diffuse = Shader::new(
-            ctx,
-            ShaderDescriptor {
-                src: include_str!("quad_diffuse.wgsl").into(),
-                textures: &[
-                    &ligth_textures.ligth,
-                    &atlas.diffuse_textures[0].view,
-                    &atlas.normal_textures[0].view,
-                ],
-                uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
-                vertex_layout: QuadInstance::desc(),
-                output_format: wgpu::TextureFormat::Bgra8Unorm,
-                blend: wgpu::BlendState::ALPHA_BLENDING,
-                depth_stencil: None,
-            },
-        );
+        let diffuse = BlendMode::ALL.map(|blend| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(
+                        include_str!("camera.wgsl"),
+                        include_str!("quad_common.wgsl"),
+                        include_str!("quad_diffuse.wgsl")
+                    )
+                    .into(),
+                    textures: &[
+                        &ligth_textures.ligth,
+                        &atlas.diffuse_textures.view,
+                        &atlas.normal_textures.view,
+                        &ligth_textures.unshadowed,
+                    ],
+                    texture_dimensions: &[
+                        wgpu::TextureViewDimension::D2,
+                        wgpu::TextureViewDimension::D2Array,
+                        wgpu::TextureViewDimension::D2Array,
+                        wgpu::TextureViewDimension::D2,
+                    ],
+                    uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                    vertex_layout: QuadInstance::desc(),
+                    output_format: wgpu::TextureFormat::Bgra8Unorm,
+                    blend: blend.state(),
+                    depth_stencil: None,
+                    ..Default::default()
+                },
+            )
+        });
 
-        let color = Shader::new(
-            ctx,
-            ShaderDescriptor {
-                src: include_str!("quad.wgsl").into(),
-                textures: &[
-                    &ligth_textures.ligth,
-                    &atlas.diffuse_textures[0].view,
-                    &atlas.normal_textures[0].view,
-                ],
-                uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
-                vertex_layout: QuadInstance::desc(),
-                output_format: wgpu::TextureFormat::Bgra8Unorm,
-                blend: wgpu::BlendState::ALPHA_BLENDING,
-                depth_stencil: None,
-            },
-        );
+        let color = BlendMode::ALL.map(|blend| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(
+                        include_str!("camera.wgsl"),
+                        include_str!("quad_common.wgsl"),
+                        include_str!("quad.wgsl")
+                    )
+                    .into(),
+                    textures: &[
+                        &ligth_textures.ligth,
+                        &atlas.diffuse_textures.view,
+                        &atlas.normal_textures.view,
+                    ],
+                    texture_dimensions: &[
+                        wgpu::TextureViewDimension::D2,
+                        wgpu::TextureViewDimension::D2Array,
+                        wgpu::TextureViewDimension::D2Array,
+                    ],
+                    uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                    vertex_layout: QuadInstance::desc(),
+                    output_format: wgpu::TextureFormat::Bgra8Unorm,
+                    blend: blend.state(),
+                    depth_stencil: None,
+                    ..Default::default()
+                },
+            )
+        });
 
         Ok(Self {
             normal,
@@ -123,22 +265,30 @@ impl QuadShader {
     }
 
     pub fn resize(&mut self, ctx: &WgpuContext, textures: &LigthTextures) {
-        self.diffuse.update_textures(
-            ctx,
-            &[
-                &textures.ligth,
-                &self.atlas.diffuse_textures[0].view,
-                &self.atlas.normal_textures[0].view,
-            ],
-        );
+        for diffuse in &mut self.diffuse {
+            diffuse.update_textures(
+                ctx,
+                &[
+                    &textures.ligth,
+                    &self.atlas.diffuse_textures.view,
+                    &self.atlas.normal_textures.view,
+                    &textures.unshadowed,
+                ],
+            );
+        }
     }
 
-    pub fn bind_ligth<'a>(&'a self, pass: &mut LigthRenderPass<'a>) {
+    /// Unlit layers still write the normals of the quads with `QuadInstance::WRITES_NORMALS`
+    pub fn bind<'a>(
+        &'a self,
+        pass: &mut LigthRenderPass<'a>,
+        lighting: Lighting,
+        blend: BlendMode,
+    ) {
         self.normal.bind(&mut pass.normal);
-        self.diffuse.bind(&mut pass.diffuse);
-    }
-
-    pub fn bind<'a>(&'a self, pass: &mut LigthRenderPass<'a>) {
-        self.color.bind(&mut pass.diffuse);
+        match lighting {
+            Lighting::Lit => self.diffuse[blend as usize].bind(&mut pass.diffuse),
+            Lighting::Unlit => self.color[blend as usize].bind(&mut pass.diffuse),
+        }
     }
 }
@@ -1,11 +1,3 @@
-struct CameraUniform {
-    pos: vec2<f32>,
-    size: vec2<f32>,
-}
-
-@group(1) @binding(0)
-var<uniform> camera: CameraUniform;
-
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
     @location(0) color: vec4<f32>,
@@ -14,19 +6,32 @@ struct VertexInput {
     @location(3) size: vec2<f32>,
     @location(4) tex_pos: vec2<f32>,
     @location(5) tex_size: vec2<f32>,
+    @location(6) page: u32,
+    @location(7) pivot: vec2<f32>,
+    @location(8) flags: u32,
+    @location(9) tint: vec4<f32>,
+    @location(10) flash: vec4<f32>,
+    @location(11) tiles: vec2<f32>,
 };
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
-    @location(0) tex_coords: vec2<f32>,
+    // Texture coordinates inside the sub-rectangle, repeated once per tile
+    @location(0) tile_coords: vec2<f32>,
     @location(1) pos: vec2<f32>,
     @location(2) color: vec4<f32>,
+    @location(3) @interpolate(flat) page: u32,
+    @location(4) tint: vec4<f32>,
+    @location(5) flash: vec4<f32>,
+    @location(6) @interpolate(flat) tex_pos: vec2<f32>,
+    @location(7) @interpolate(flat) tex_size: vec2<f32>,
 };
 
 fn quad_mesh(i: u32) -> vec2<f32> {
     return vec2(f32(i & 1u), f32((i & 2u) >> 1u));
 }
 
+
 @vertex
 fn vs_main(
     model: VertexInput,
@@ -35,19 +40,24 @@ fn vs_main(
 
     let coord = quad_mesh(model.vertex_index);
 
-    out.tex_coords = vec2(coord.x, 1. - coord.y) * model.tex_size + model.tex_pos;
+    out.tile_coords = vec2(coord.x, 1. - coord.y) * model.tiles;
+    out.tex_pos = model.tex_pos;
+    out.tex_size = model.tex_size;
+    out.page = model.page;
     
     let c = cos(model.angle);
     let s = sin(model.angle);
     let rotation_matrix = mat2x2<f32>(c, -s, s, c);
     
-    out.pos = rotation_matrix * (coord - 0.5) * model.size + model.pos;
-    out.pos += camera.pos;
-    out.pos *= camera.size;
+    let flip = flip_scale(model.flags);
+    out.pos = rotation_matrix * ((coord - 0.5 - model.pivot) * model.size * flip) + model.pos;
+    out.pos = camera_transform(out.pos);
     
     out.clip_position = vec4<f32>(out.pos, 0., 1.);
     out.pos = out.pos * vec2(0.5, -0.5) + 0.5;
     out.color = model.color.abgr;
+    out.tint = model.tint.abgr;
+    out.flash = model.flash.abgr;
     
     return out;
 }
@@ -58,7 +68,7 @@ fn vs_main(
 var tex_sampler: sampler;
 
 @group(0) @binding(2)
-var atlas_tex: texture_2d<f32>;
+var atlas_tex: texture_2d_array<f32>;
 
 fn grayscale(color: vec3<f32>) -> f32 {
     return dot(color, vec3(0.2126, 0.7152, 0.0722));
@@ -66,8 +76,13 @@ fn grayscale(color: vec3<f32>) -> f32 {
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let tex = textureSample(atlas_tex, tex_sampler, in.tex_coords);
-    let color = select(tex, in.color, in.tex_coords.x < 0.);
-    return vec4(mix(color.rgb, in.color.rgb, in.color.a), color.a);
+    let tex = sample_tiled(atlas_tex, in);
+    var color = select(tex, in.color, in.tex_pos.x < 0.);
+    color = vec4(mix(color.rgb, in.color.rgb, in.color.a), color.a) * in.tint;
+    
+    let rgb = color.rgb + in.flash.rgb * in.flash.a;
+    
+    // Premultiplied alpha
+    return vec4(rgb * color.a, color.a);
 }
  
\ No newline at end of file
@@ -0,0 +1,17 @@
+const FLIP_X: u32 = 1u;
+const FLIP_Y: u32 = 2u;
+
+fn flip_scale(flags: u32) -> vec2<f32> {
+    return select(vec2(1.), vec2(-1.), vec2((flags & FLIP_X) != 0u, (flags & FLIP_Y) != 0u));
+}
+
+// Wraps the coordinates inside the atlas sub-rectangle. The gradients
+// of the unwrapped coordinates keep the mip level continuous at the seams.
+// Every quad shader declares `tex_sampler` and a `VertexOutput` with the tile fields
+fn sample_tiled(tex: texture_2d_array<f32>, in: VertexOutput) -> vec4<f32> {
+    let tex_coords = fract(in.tile_coords) * in.tex_size + in.tex_pos;
+    let ddx = dpdx(in.tile_coords) * in.tex_size;
+    let ddy = dpdy(in.tile_coords) * in.tex_size;
+    return textureSampleGrad(tex, tex_sampler, tex_coords, in.page, ddx, ddy);
+}
+
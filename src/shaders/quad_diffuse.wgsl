@@ -1,11 +1,3 @@
-struct CameraUniform {
-    pos: vec2<f32>,
-    size: vec2<f32>,
-}
-
-@group(1) @binding(0)
-var<uniform> camera: CameraUniform;
-
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
     @location(0) color: vec4<f32>,
@@ -14,19 +6,36 @@ struct VertexInput {
     @location(3) size: vec2<f32>,
     @location(4) tex_pos: vec2<f32>,
     @location(5) tex_size: vec2<f32>,
+    @location(6) page: u32,
+    @location(7) pivot: vec2<f32>,
+    @location(8) flags: u32,
+    @location(9) tint: vec4<f32>,
+    @location(10) flash: vec4<f32>,
+    @location(11) tiles: vec2<f32>,
 };
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
-    @location(0) tex_coords: vec2<f32>,
+    // Texture coordinates inside the sub-rectangle, repeated once per tile
+    @location(0) tile_coords: vec2<f32>,
     @location(1) pos: vec2<f32>,
     @location(2) color: vec4<f32>,
+    @location(3) @interpolate(flat) page: u32,
+    @location(4) tint: vec4<f32>,
+    @location(5) flash: vec4<f32>,
+    @location(6) @interpolate(flat) tex_pos: vec2<f32>,
+    @location(7) @interpolate(flat) tex_size: vec2<f32>,
+    @location(8) @interpolate(flat) flags: u32,
 };
 
 fn quad_mesh(i: u32) -> vec2<f32> {
     return vec2(f32(i & 1u), f32((i & 2u) >> 1u));
 }
 
+const RECEIVES_LIGTH: u32 = 4u;
+const RECEIVES_SHADOWS: u32 = 16u;
+const SELF_ILLUMINATED: u32 = 32u;
+
 @vertex
 fn vs_main(
     model: VertexInput,
@@ -35,19 +44,25 @@ fn vs_main(
 
     let coord = quad_mesh(model.vertex_index);
 
-    out.tex_coords = vec2(coord.x, 1. - coord.y) * model.tex_size + model.tex_pos;
+    out.tile_coords = vec2(coord.x, 1. - coord.y) * model.tiles;
+    out.tex_pos = model.tex_pos;
+    out.tex_size = model.tex_size;
+    out.page = model.page;
     
     let c = cos(model.angle);
     let s = sin(model.angle);
     let rotation_matrix = mat2x2<f32>(c, -s, s, c);
     
-    out.pos = rotation_matrix * (coord - 0.5) * model.size + model.pos;
-    out.pos += camera.pos;
-    out.pos *= camera.size;
+    let flip = flip_scale(model.flags);
+    out.pos = rotation_matrix * ((coord - 0.5 - model.pivot) * model.size * flip) + model.pos;
+    out.pos = camera_transform(out.pos);
     
     out.clip_position = vec4<f32>(out.pos, 0., 1.);
     out.pos = out.pos * vec2(0.5, -0.5) + 0.5;
-    out.color = model.color;
+    out.color = model.color.abgr;
+    out.tint = model.tint.abgr;
+    out.flash = model.flash.abgr;
+    out.flags = model.flags;
     
     return out;
 }
@@ -61,10 +76,13 @@ var tex_sampler: sampler;
 var ligth_tex: texture_2d<f32>;
 
 @group(0) @binding(2)
-var atlas_tex: texture_2d<f32>;
+var atlas_tex: texture_2d_array<f32>;
 
 @group(0) @binding(3)
-var dark_atlas_tex: texture_2d<f32>;
+var dark_atlas_tex: texture_2d_array<f32>;
+
+@group(0) @binding(4)
+var unshadowed_tex: texture_2d<f32>;
 
 fn grayscale(color: vec3<f32>) -> f32 {
     return dot(color, vec3(0.2126, 0.7152, 0.0722));
@@ -72,22 +90,38 @@ fn grayscale(color: vec3<f32>) -> f32 {
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let ligth_enc = textureSample(ligth_tex, tex_sampler, in.pos).rgb;
+    var ligth_enc = textureSample(ligth_tex, tex_sampler, in.pos).rgb;
+    // The unshadowed target is only drawn when some quad needs it
+    if (in.flags & RECEIVES_SHADOWS) == 0u {
+        ligth_enc = textureSampleLevel(unshadowed_tex, tex_sampler, in.pos, 0.).rgb;
+    }
     // let ligth = ligth_enc * 4.;
-    let ligth = ligth_enc * ligth_enc * 8.;
-    
+    var ligth = ligth_enc * ligth_enc * 8.;
+
+    if (in.flags & RECEIVES_LIGTH) == 0u {
+        ligth = vec3(1.);
+    } else if (in.flags & SELF_ILLUMINATED) != 0u {
+        ligth = max(ligth, vec3(1.));
+    }
 
     let l = vec4(ligth, grayscale(ligth));
     let w1 = max(vec4(0.), 1. - l);//max(vec4(0.), 4. * (l - l * l));
     let w2 = l;//max(vec4(0.), 2. * l - 1.);
     
-    let color = textureSample(atlas_tex, tex_sampler, in.tex_coords);
+    let tex = sample_tiled(atlas_tex, in);
+    var color = select(tex, in.color, in.tex_pos.x < 0.);
+    color = vec4(mix(color.rgb, in.color.rgb, in.color.a), color.a) * in.tint;
+    
     let dark_color = vec4(vec3(grayscale(color.rgb)) * 0.3, color.a);
     
-    // let dark_color = textureSample(dark_atlas_tex, tex_sampler, in.tex_coords);
+    // let dark_color = sample_tiled(dark_atlas_tex, in);
 
     // return dark_color * max(vec4(0.), 1. - ligth_mask) + color * ligth_mask;
 
-    return dark_color * w1 + color * w2;
+    // The flash is not affected by the ligth
+    let rgb = (dark_color * w1 + color * w2).rgb + in.flash.rgb * in.flash.a;
+    
+    // Premultiplied alpha
+    return vec4(rgb * color.a, color.a);
 }
  
\ No newline at end of file
@@ -1,11 +1,3 @@
-struct CameraUniform {
-    pos: vec2<f32>,
-    size: vec2<f32>,
-}
-
-@group(1) @binding(0)
-var<uniform> camera: CameraUniform;
-
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
     @location(0) color: vec4<f32>,
@@ -14,17 +6,34 @@ struct VertexInput {
     @location(3) size: vec2<f32>,
     @location(4) tex_pos: vec2<f32>,
     @location(5) tex_size: vec2<f32>,
+    @location(6) page: u32,
+    @location(7) pivot: vec2<f32>,
+    @location(8) flags: u32,
+    @location(9) tint: vec4<f32>,
+    @location(10) flash: vec4<f32>,
+    @location(11) tiles: vec2<f32>,
 };
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
-    @location(0) tex_coords: vec2<f32>,
+    // Texture coordinates inside the sub-rectangle, repeated once per tile
+    @location(0) tile_coords: vec2<f32>,
+    @location(1) rotation: vec2<f32>,
+    @location(2) mirror: vec2<f32>,
+    @location(3) @interpolate(flat) page: u32,
+    @location(4) alpha: f32,
+    @location(5) @interpolate(flat) solid: u32,
+    @location(6) @interpolate(flat) tex_pos: vec2<f32>,
+    @location(7) @interpolate(flat) tex_size: vec2<f32>,
+    @location(8) @interpolate(flat) flags: u32,
 };
 
 fn quad_mesh(i: u32) -> vec2<f32> {
     return vec2(f32(i & 1u), f32((i & 2u) >> 1u));
 }
 
+const WRITES_NORMALS: u32 = 8u;
+
 @vertex
 fn vs_main(
     model: VertexInput,
@@ -33,15 +42,25 @@ fn vs_main(
 
     let coord = quad_mesh(model.vertex_index);
 
-    out.tex_coords = vec2(coord.x, 1. - coord.y) * model.tex_size + model.tex_pos;
+    out.tile_coords = vec2(coord.x, 1. - coord.y) * model.tiles;
+    out.tex_pos = model.tex_pos;
+    out.tex_size = model.tex_size;
+    out.page = model.page;
+    out.alpha = model.tint.abgr.a;
+    out.solid = u32(model.tex_pos.x < 0.);
+    out.flags = model.flags;
     
     let c = cos(model.angle);
     let s = sin(model.angle);
     let rotation_matrix = mat2x2<f32>(c, -s, s, c);
-    var pos = rotation_matrix * (coord - 0.5) * model.size + model.pos;
+    let flip = flip_scale(model.flags);
+    var pos = rotation_matrix * ((coord - 0.5 - model.pivot) * model.size * flip) + model.pos;
+
+    // Flips and negative sizes mirror the quad, so the normals have to be mirrored too
+    out.rotation = vec2(c, s);
+    out.mirror = select(vec2(1.), vec2(-1.), model.size < vec2(0.)) * flip;
     
-    pos += camera.pos;
-    pos *= camera.size;
+    pos = camera_transform(pos);
     
     out.clip_position = vec4<f32>(pos, 0., 1.);
     return out;
@@ -53,11 +72,29 @@ fn vs_main(
 var tex_sampler: sampler;
 
 @group(0) @binding(1)
-var atlas_tex: texture_2d<f32>;
+var atlas_tex: texture_2d_array<f32>;
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let color = textureSample(atlas_tex, tex_sampler, in.tex_coords);
-    return vec4(color.rgb, select(0., 1., color.a > 0.3));
+    // Keeps the normals below the quad
+    if (in.flags & WRITES_NORMALS) == 0u {
+        discard;
+    }
+
+    var color = sample_tiled(atlas_tex, in);
+    // Solid color quads are flat
+    color = select(color, vec4(0.5, 0.5, 1., 1.), in.solid != 0u);
+
+    // Transform the tangent space normal to world space
+    let c = in.rotation.x;
+    let s = in.rotation.y;
+    let rotation_matrix = mat2x2<f32>(c, -s, s, c);
+
+    var normal = color.rgb * 2. - 1.;
+    normal = vec3(rotation_matrix * (normal.xy * in.mirror), normal.z);
+
+    // Premultiplied alpha, faded by the alpha of the tint
+    let alpha = select(0., 1., color.a > 0.3) * in.alpha;
+    return vec4((normal * 0.5 + 0.5) * alpha, alpha);
 }
  
\ No newline at end of file
@@ -0,0 +1,236 @@
+use super::{BlendMode, Lighting};
+use crate::ligth_pipeline::*;
+use crate::math::*;
+use crate::wgpu_components::*;
+
+/// Draws analytic shapes with antialiased edges, see `ShapeInstance`
+pub struct ShapeShader {
+    color: [Shader; 2],
+    diffuse: [Shader; 2],
+    normal: Shader,
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShapeKind {
+    /// Fills `size`, a circle when both sides are equal
+    Ellipse = 0,
+    /// Corners rounded by `radius`
+    RoundedRect = 1,
+    /// Ellipse edge of width `thickness`
+    Ring = 2,
+    /// Circular stroke of width `thickness` with round caps, following `arc`
+    Arc = 3,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::NoUninit)]
+pub struct ShapeInstance {
+    /// 0xRRGGBBAA
+    pub color: u32,
+    /// 0xRRGGBBAA
+    pub outline_color: u32,
+    pub angle: f32,
+    pub pos: Vec2,
+    pub size: Vec2,
+    /// A `ShapeKind`
+    pub kind: u32,
+    pub radius: f32,
+    pub thickness: f32,
+    /// Start angle and counterclockwise sweep in radians
+    pub arc: Vec2,
+    /// Width of the outline drawn inside the edge
+    pub outline: f32,
+}
+
+impl ShapeInstance {
+    const ATTRIBS: [wgpu::VertexAttribute; 10] = wgpu::vertex_attr_array![
+        0 => Unorm8x4,  // color
+        1 => Unorm8x4,  // outline_color
+        2 => Float32,   // angle
+        3 => Float32x2, // pos
+        4 => Float32x2, // size
+        5 => Uint32,    // kind
+        6 => Float32,   // radius
+        7 => Float32,   // thickness
+        8 => Float32x2, // arc
+        9 => Float32,   // outline
+    ];
+
+    pub fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<ShapeInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+
+    pub fn new(kind: ShapeKind, pos: Vec2, size: Vec2, color: u32) -> Self {
+        Self {
+            color,
+            outline_color: 0,
+            angle: 0.,
+            pos,
+            size,
+            kind: kind as u32,
+            radius: 0.,
+            thickness: 0.,
+            arc: Vec2::zero(),
+            outline: 0.,
+        }
+    }
+
+    pub fn circle(pos: Vec2, radius: f32, color: u32) -> Self {
+        Self::new(
+            ShapeKind::Ellipse,
+            pos,
+            Vec2::new(radius, radius) * 2.,
+            color,
+        )
+    }
+
+    pub fn ellipse(pos: Vec2, size: Vec2, color: u32) -> Self {
+        Self::new(ShapeKind::Ellipse, pos, size, color)
+    }
+
+    pub fn rounded_rect(pos: Vec2, size: Vec2, radius: f32, color: u32) -> Self {
+        Self {
+            radius,
+            ..Self::new(ShapeKind::RoundedRect, pos, size, color)
+        }
+    }
+
+    /// `radius` is the outer radius
+    pub fn ring(pos: Vec2, radius: f32, thickness: f32, color: u32) -> Self {
+        Self {
+            thickness,
+            ..Self::new(ShapeKind::Ring, pos, Vec2::new(radius, radius) * 2., color)
+        }
+    }
+
+    /// `radius` is the outer radius, `start` and `sweep` are in radians.
+    /// A negative `sweep` goes the other way from `start`
+    pub fn arc(pos: Vec2, radius: f32, thickness: f32, start: f32, sweep: f32, color: u32) -> Self {
+        // The shader expects a positive sweep
+        let (start, sweep) = if sweep < 0. {
+            (start + sweep, -sweep)
+        } else {
+            (start, sweep)
+        };
+        let sweep = sweep.min(std::f32::consts::TAU);
+
+        Self {
+            thickness,
+            arc: Vec2::new(start, sweep),
+            ..Self::new(ShapeKind::Arc, pos, Vec2::new(radius, radius) * 2., color)
+        }
+    }
+
+    pub fn with_outline(self, outline: f32, outline_color: u32) -> Self {
+        Self {
+            outline,
+            outline_color,
+            ..self
+        }
+    }
+}
+
+impl Disable for ShapeInstance {
+    fn disable(&mut self) {
+        self.size = Vec2::zero();
+    }
+}
+
+impl ShapeShader {
+    pub fn new(ctx: &WgpuContext, ligth_textures: &LigthTextures) -> Self {
+        let normal = Shader::new(
+            ctx,
+            ShaderDescriptor {
+                src: concat!(
+                    include_str!("camera.wgsl"),
+                    include_str!("shape_common.wgsl"),
+                    include_str!("shape_normal.wgsl")
+                )
+                .into(),
+                textures: &[],
+                texture_dimensions: &[],
+                uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                vertex_layout: ShapeInstance::desc(),
+                output_format: wgpu::TextureFormat::Rgb10a2Unorm,
+                blend: wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                depth_stencil: None,
+                ..Default::default()
+            },
+        );
+
+        let diffuse = BlendMode::ALL.map(|blend| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(
+                        include_str!("camera.wgsl"),
+                        include_str!("shape_common.wgsl"),
+                        include_str!("shape_diffuse.wgsl")
+                    )
+                    .into(),
+                    textures: &[&ligth_textures.ligth],
+                    texture_dimensions: &[wgpu::TextureViewDimension::D2],
+                    uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                    vertex_layout: ShapeInstance::desc(),
+                    output_format: wgpu::TextureFormat::Bgra8Unorm,
+                    blend: blend.state(),
+                    depth_stencil: None,
+                    ..Default::default()
+                },
+            )
+        });
+
+        let color = BlendMode::ALL.map(|blend| {
+            Shader::new(
+                ctx,
+                ShaderDescriptor {
+                    src: concat!(
+                        include_str!("camera.wgsl"),
+                        include_str!("shape_common.wgsl"),
+                        include_str!("shape.wgsl")
+                    )
+                    .into(),
+                    textures: &[],
+                    texture_dimensions: &[],
+                    uniforms: &[&Uniform::new_layout(ctx, wgpu::ShaderStages::VERTEX)],
+                    vertex_layout: ShapeInstance::desc(),
+                    output_format: wgpu::TextureFormat::Bgra8Unorm,
+                    blend: blend.state(),
+                    depth_stencil: None,
+                    ..Default::default()
+                },
+            )
+        });
+
+        Self {
+            normal,
+            diffuse,
+            color,
+        }
+    }
+
+    pub fn resize(&mut self, ctx: &WgpuContext, textures: &LigthTextures) {
+        for diffuse in &mut self.diffuse {
+            diffuse.update_textures(ctx, &[&textures.ligth]);
+        }
+    }
+
+    /// Unlit layers still write the normals of the shapes in the normal pass
+    pub fn bind<'a>(
+        &'a self,
+        pass: &mut LigthRenderPass<'a>,
+        lighting: Lighting,
+        blend: BlendMode,
+    ) {
+        self.normal.bind(&mut pass.normal);
+        match lighting {
+            Lighting::Lit => self.diffuse[blend as usize].bind(&mut pass.diffuse),
+            Lighting::Unlit => self.color[blend as usize].bind(&mut pass.diffuse),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = shape_color(in, sdf(in, in.local));
+
+    // Premultiplied alpha
+    return vec4(color.rgb * color.a, color.a);
+}
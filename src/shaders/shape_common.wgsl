@@ -0,0 +1,135 @@
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+    @location(0) color: vec4<f32>,
+    @location(1) outline_color: vec4<f32>,
+    @location(2) angle: f32,
+    @location(3) pos: vec2<f32>,
+    @location(4) size: vec2<f32>,
+    @location(5) kind: u32,
+    @location(6) radius: f32,
+    @location(7) thickness: f32,
+    @location(8) arc: vec2<f32>,
+    @location(9) outline: f32,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    // Position relative to the center of the shape, without rotation
+    @location(0) local: vec2<f32>,
+    @location(1) screen_pos: vec2<f32>,
+    @location(2) rotation: vec2<f32>,
+    @location(3) color: vec4<f32>,
+    @location(4) outline_color: vec4<f32>,
+    @location(5) @interpolate(flat) half_size: vec2<f32>,
+    @location(6) @interpolate(flat) kind: u32,
+    @location(7) @interpolate(flat) radius: f32,
+    @location(8) @interpolate(flat) thickness: f32,
+    @location(9) @interpolate(flat) arc: vec2<f32>,
+    @location(10) @interpolate(flat) outline: f32,
+};
+
+fn quad_mesh(i: u32) -> vec2<f32> {
+    return vec2(f32(i & 1u), f32((i & 2u) >> 1u));
+}
+
+@vertex
+fn vs_main(
+    model: VertexInput,
+) -> VertexOutput {
+    var out: VertexOutput;
+
+    let coord = quad_mesh(model.vertex_index);
+
+    let c = cos(model.angle);
+    let s = sin(model.angle);
+    let rotation_matrix = mat2x2<f32>(c, -s, s, c);
+
+    out.local = (coord - 0.5) * model.size;
+    var pos = rotation_matrix * out.local + model.pos;
+    pos = camera_transform(pos);
+
+    out.clip_position = vec4<f32>(pos, 0., 1.);
+    out.screen_pos = pos * vec2(0.5, -0.5) + 0.5;
+    out.rotation = vec2(c, s);
+
+    out.color = model.color.abgr;
+    out.outline_color = model.outline_color.abgr;
+    out.half_size = model.size * 0.5;
+    out.kind = model.kind;
+    out.radius = model.radius;
+    out.thickness = model.thickness;
+    out.arc = model.arc;
+    out.outline = model.outline;
+
+    return out;
+}
+
+
+
+const TAU: f32 = 6.28318530718;
+
+fn sdf_ellipse(p: vec2<f32>, half_size: vec2<f32>) -> f32 {
+    if half_size.x == half_size.y {
+        return length(p) - half_size.x;
+    }
+    // Approximation, exact on the edge
+    let k1 = length(p / half_size);
+    let k2 = max(length(p / (half_size * half_size)), 0.00001);
+    return k1 * (k1 - 1.) / k2;
+}
+
+fn sdf_rounded_rect(p: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    let r = min(radius, min(half_size.x, half_size.y));
+    let q = abs(p) - half_size + r;
+    return length(max(q, vec2(0.))) + min(max(q.x, q.y), 0.) - r;
+}
+
+fn sdf_arc(p: vec2<f32>, half_size: vec2<f32>, thickness: f32, arc: vec2<f32>) -> f32 {
+    // Radius of the center of the stroke
+    let r = min(half_size.x, half_size.y) - thickness * 0.5;
+
+    let angle = atan2(p.y, p.x) - arc.x;
+    if angle - floor(angle / TAU) * TAU <= arc.y {
+        return abs(length(p) - r) - thickness * 0.5;
+    }
+
+    // Distance to the round caps
+    let start = vec2(cos(arc.x), sin(arc.x)) * r;
+    let end = vec2(cos(arc.x + arc.y), sin(arc.x + arc.y)) * r;
+    return min(length(p - start), length(p - end)) - thickness * 0.5;
+}
+
+// Signed distance to the edge of the shape, negative inside.
+// The kinds match `ShapeKind`
+fn sdf(in: VertexOutput, p: vec2<f32>) -> f32 {
+    switch in.kind {
+        // Rounded rect
+        case 1u: {
+            return sdf_rounded_rect(p, in.half_size, in.radius);
+        }
+        // Ring
+        case 2u: {
+            let d = sdf_ellipse(p, in.half_size);
+            return abs(d + in.thickness * 0.5) - in.thickness * 0.5;
+        }
+        // Arc
+        case 3u: {
+            return sdf_arc(p, in.half_size, in.thickness, in.arc);
+        }
+        // Ellipse
+        default: {
+            return sdf_ellipse(p, in.half_size);
+        }
+    }
+}
+
+// Antialiased coverage of the shape and the color with the outline
+fn shape_color(in: VertexOutput, d: f32) -> vec4<f32> {
+    let aa = max(fwidth(d), 0.00001);
+    let coverage = clamp(-d / aa, 0., 1.);
+
+    let fill = clamp(-(d + in.outline) / aa, 0., 1.);
+    var color = mix(in.outline_color, in.color, select(1., fill, in.outline > 0.));
+    color.a *= coverage;
+    return color;
+}
@@ -0,0 +1,29 @@
+
+
+@group(0) @binding(0)
+var tex_sampler: sampler;
+
+@group(0) @binding(1)
+var ligth_tex: texture_2d<f32>;
+
+fn grayscale(color: vec3<f32>) -> f32 {
+    return dot(color, vec3(0.2126, 0.7152, 0.0722));
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let ligth_enc = textureSample(ligth_tex, tex_sampler, in.screen_pos).rgb;
+    let ligth = ligth_enc * ligth_enc * 8.;
+
+    let l = vec4(ligth, grayscale(ligth));
+    let w1 = max(vec4(0.), 1. - l);
+    let w2 = l;
+
+    let color = shape_color(in, sdf(in, in.local));
+    let dark_color = vec4(vec3(grayscale(color.rgb)) * 0.3, color.a);
+
+    let rgb = (dark_color * w1 + color * w2).rgb;
+
+    // Premultiplied alpha
+    return vec4(rgb * color.a, color.a);
+}
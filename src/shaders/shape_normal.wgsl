@@ -0,0 +1,37 @@
+
+
+// Width of the rounded edge, relative to the smallest half size of the shape
+const BEVEL: f32 = 0.3;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let d = sdf(in, in.local);
+
+    // Gradient of the distance, points outwards
+    let e = 0.0005;
+    let gradient = vec2(
+        sdf(in, in.local + vec2(e, 0.)) - sdf(in, in.local - vec2(e, 0.)),
+        sdf(in, in.local + vec2(0., e)) - sdf(in, in.local - vec2(0., e)),
+    );
+    let direction = gradient / max(length(gradient), 0.00001);
+
+    // The strokes of rings and arcs are beveled from both sides
+    var bevel = min(in.half_size.x, in.half_size.y) * BEVEL;
+    if in.kind >= 2u {
+        bevel = in.thickness * 0.5;
+    }
+
+    // Rises from the edge as a quarter circle
+    let t = 1. - clamp(-d / bevel, 0., 1.);
+    var normal = vec3(direction * t, sqrt(max(1. - t * t, 0.)));
+
+    // Transform to world space
+    let c = in.rotation.x;
+    let s = in.rotation.y;
+    let rotation_matrix = mat2x2<f32>(c, -s, s, c);
+    normal = normalize(vec3(rotation_matrix * normal.xy, normal.z + 0.001));
+
+    // Premultiplied alpha
+    let alpha = select(0., 1., shape_color(in, d).a > 0.3);
+    return vec4((normal * 0.5 + 0.5) * alpha, alpha);
+}
@@ -0,0 +1,116 @@
+use crate::error::*;
+use crate::texture_atlas::*;
+use std::fmt::Display;
+use std::str::FromStr;
+
+impl Sprite {
+    pub fn name(self) -> &'static str {
+        Self::NAMES[self as usize]
+    }
+
+    /// Ids are only stable while the assets don't change,
+    /// prefer the name for data that outlives a build
+    pub fn id(self) -> u16 {
+        self as u16
+    }
+
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    pub fn from_name(name: &str) -> ErrResult<Self> {
+        if let Ok(index) = Self::NAMES.binary_search(&name) {
+            return Ok(Self::ALL[index]);
+        }
+
+        let closest = Self::NAMES
+            .iter()
+            .map(|candidate| (edit_distance(name, candidate), candidate))
+            .min();
+
+        match closest {
+            Some((distance, candidate)) if distance <= (name.len() / 3).max(2) => {
+                Err(format!("Unknown sprite \"{name}\", did you mean \"{candidate}\"?").into())
+            }
+            _ => Err(format!("Unknown sprite \"{name}\"").into()),
+        }
+    }
+}
+
+impl FromStr for Sprite {
+    type Err = Error;
+
+    fn from_str(name: &str) -> ErrResult<Self> {
+        Self::from_name(name)
+    }
+}
+
+impl Display for Sprite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl TextureAtlas {
+    /// Runtime lookup of a sprite by its asset name (without extension)
+    pub fn get(name: &str) -> ErrResult<TextureAtlasView> {
+        Ok(Sprite::from_name(name)?.view())
+    }
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + (a_char != *b_char) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Debug output of the error, with the quotes escaped
+    fn message(name: &str) -> String {
+        format!("{:?}", Sprite::from_name(name).unwrap_err())
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for sprite in Sprite::ALL {
+            assert_eq!(Sprite::from_name(sprite.name()).unwrap(), sprite);
+            assert_eq!(sprite.to_string().parse::<Sprite>().unwrap(), sprite);
+            assert_eq!(Sprite::from_id(sprite.id()), Some(sprite));
+        }
+        assert_eq!(Sprite::from_id(Sprite::ALL.len() as u16), None);
+    }
+
+    #[test]
+    fn suggests_close_names() {
+        assert!(message("block_sq").contains(r#"did you mean \"block_sq2\"?"#));
+        assert!(message("trianlges").contains(r#"did you mean \"triangles\"?"#));
+        assert!(!message("mushroom").contains("did you mean"));
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("bow", ""), 3);
+        assert_eq!(edit_distance("", "bow"), 3);
+        assert_eq!(edit_distance("bow", "bow"), 0);
+        assert_eq!(edit_distance("bow", "bowl"), 1);
+        assert_eq!(edit_distance("arrow", "arow"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
@@ -1,39 +1,51 @@
 // THIS CODE IS GENERATED BY THE BUILD SCRIPT.
 // ANY CHANGE WILL BE OVERWRITTEN.
 
+use crate::animation::*;
 use crate::error::*;
 use crate::math::*;
 use crate::wgpu_components::*;
 
+pub const MIP_LEVELS: u32 = 4;
+
+/// Every page of the atlas is a layer of the texture arrays
 pub struct TextureAtlas {
-    pub diffuse_textures: [Texture; 1],
-    pub normal_textures: [Texture; 1],
+    pub diffuse_textures: Texture,
+    pub normal_textures: Texture,
 }
 
 #[derive(Copy, Clone)]
 pub struct TextureAtlasView {
     pub pos: Vec2,
     pub size: Vec2,
+    pub page: u32,
+    /// Aspect ratio of the untrimmed image
     ratio: f32,
+    /// Center of the trimmed image relative to the untrimmed one (in untrimmed units)
+    pub trim_offset: Vec2,
+    /// Size of the trimmed image relative to the untrimmed one
+    pub trim_scale: Vec2,
 }
 
 impl TextureAtlas {
     pub fn load(ctx: &WgpuContext) -> ErrResult<Self> {
         Ok(Self {
-            diffuse_textures: [
-                Texture::from_bytes(
-                    ctx,
+            diffuse_textures: Texture::from_bytes_array(
+                ctx,
+                &[
                     include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/atlas/diffuse-0.webp")),
-                    "Diffuse Texture 0",
-                )?,
-            ],
-            normal_textures: [
-                Texture::from_bytes(
-                    ctx,
+                ],
+                MIP_LEVELS,
+                "Diffuse Texture Atlas",
+            )?,
+            normal_textures: Texture::from_bytes_array(
+                ctx,
+                &[
                     include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/atlas/normal-0.webp")),
-                    "Normal Texture 0",
-                )?,
-            ],
+                ],
+                MIP_LEVELS,
+                "Normal Texture Atlas",
+            )?,
         })
     }
 
@@ -41,82 +53,144 @@ impl TextureAtlas {
     pub fn view_arrow() -> TextureAtlasView {
         TextureAtlasView {
             pos: Vec2::new(0f32, 0f32),
-            size: Vec2::new(0.045027625f32, 0.031583104f32),
+            size: Vec2::new(0.043374136f32, 0.031298906f32),
+            page: 0,
             ratio: 2.0375f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
     pub fn view_block_sq2() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.045303866f32, 0f32),
-            size: Vec2::new(0.10359116f32, 0.1480458f32),
+            pos: Vec2::new(0.04976051f32, 0f32),
+            size: Vec2::new(0.09978712f32, 0.14671361f32),
+            page: 0,
             ratio: 1f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
     pub fn view_block_sq3() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.14917128f32, 0f32),
-            size: Vec2::new(0.10359116f32, 0.1480458f32),
+            pos: Vec2::new(0.155934f32, 0f32),
+            size: Vec2::new(0.09978712f32, 0.14671361f32),
+            page: 0,
             ratio: 1f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
     pub fn view_block_sq4() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.25303867f32, 0f32),
-            size: Vec2::new(0.10359116f32, 0.1480458f32),
+            pos: Vec2::new(0.2621075f32, 0f32),
+            size: Vec2::new(0.09978712f32, 0.14671361f32),
+            page: 0,
             ratio: 1f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
     pub fn view_bow() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.3569061f32, 0f32),
-            size: Vec2::new(0.047513813f32, 0.106592976f32),
+            pos: Vec2::new(0.368281f32, 0f32),
+            size: Vec2::new(0.045769025f32, 0.1056338f32),
+            page: 0,
             ratio: 0.63703704f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
-    pub fn view_bow_charge_0() -> TextureAtlasView {
+    pub fn view_bow_charge_frame0() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.40469614f32, 0f32),
-            size: Vec2::new(0.029281767f32, 0.13067509f32),
+            pos: Vec2::new(0.4204364f32, 0f32),
+            size: Vec2::new(0.028206494f32, 0.12949921f32),
+            page: 0,
             ratio: 0.3202417f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
-    pub fn view_bow_charge_1() -> TextureAtlasView {
+    pub fn view_bow_charge_frame1() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.43425414f32, 0f32),
-            size: Vec2::new(0.020718232f32, 0.15752073f32),
+            pos: Vec2::new(0.45502928f32, 0f32),
+            size: Vec2::new(0.019957425f32, 0.15610328f32),
+            page: 0,
             ratio: 0.18796992f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
     pub fn view_tektur_regular() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.45524862f32, 0f32),
-            size: Vec2::new(0.13425414f32, 0.19107777f32),
-            ratio: 1.0041323f32,
+            pos: Vec2::new(0.48137307f32, 0f32),
+            size: Vec2::new(0.12932411f32, 0.18935837f32),
+            page: 0,
+            ratio: 1.0041152f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(0.99590164, 0.9958848),
         }
     }
     #[allow(dead_code)]
     pub fn view_text_button() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.589779f32, 0f32),
-            size: Vec2::new(0.18342541f32, 0.04500592f32),
+            pos: Vec2::new(0.61708355f32, 0f32),
+            size: Vec2::new(0.17668973f32, 0.044600938f32),
+            page: 0,
             ratio: 5.8245616f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
     #[allow(dead_code)]
     pub fn view_triangles() -> TextureAtlasView {
         TextureAtlasView {
-            pos: Vec2::new(0.43425414f32, 0.19147256f32),
-            size: Vec2::new(0.56574583f32, 0.8085274f32),
+            pos: Vec2::new(0.45502928f32, 0.19874804f32),
+            size: Vec2::new(0.54497075f32, 0.80125195f32),
+            page: 0,
             ratio: 1f32,
+            trim_offset: Vec2::new(0., 0.),
+            trim_scale: Vec2::new(1., 1.),
         }
     }
+    #[allow(dead_code)]
+    pub fn slice_text_button() -> NineSliceView {
+        NineSliceView {
+            view: TextureAtlas::view_text_button(),
+            insets_min: Vec2::new(0.42105263, 0.),
+            insets_max: Vec2::new(0.42105263, 0.),
+            margins_min: Vec2::new(0., 0.),
+            margins_max: Vec2::new(0., 0.),
+        }
+    }
+    #[allow(dead_code)]
+    pub fn clip_bow_charge() -> AnimationClip {
+        AnimationClip {
+            frames: &[Sprite::BowChargeFrame0, Sprite::BowChargeFrame1],
+            durations: &[0.4f32, 0.4f32],
+            mode: AnimationMode::PingPong,
+        }
+    }
+}
+
+/// A sprite that stretches the center and keeps the borders
+#[derive(Copy, Clone)]
+pub struct NineSliceView {
+    pub view: TextureAtlasView,
+    /// Left and bottom borders of the trimmed image (in units of the untrimmed height)
+    pub insets_min: Vec2,
+    /// Right and top borders of the trimmed image (in units of the untrimmed height)
+    pub insets_max: Vec2,
+    /// Left and bottom space removed by the trim (in units of the untrimmed height)
+    pub margins_min: Vec2,
+    /// Right and top space removed by the trim (in units of the untrimmed height)
+    pub margins_max: Vec2,
 }
 
 impl TextureAtlasView {
@@ -125,3 +199,65 @@ impl TextureAtlasView {
         Vec2::new(self.ratio, 1.)
     }
 }
+
+/// Every image of the atlas, the discriminant is the sprite id
+#[allow(dead_code)]
+#[repr(u16)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Sprite {
+    Arrow,
+    BlockSq2,
+    BlockSq3,
+    BlockSq4,
+    Bow,
+    BowChargeFrame0,
+    BowChargeFrame1,
+    TekturRegular,
+    TextButton,
+    Triangles,
+}
+
+impl Sprite {
+    /// Sorted by name, indexed by the sprite id
+    pub const ALL: [Sprite; 10] = [
+        Sprite::Arrow,
+        Sprite::BlockSq2,
+        Sprite::BlockSq3,
+        Sprite::BlockSq4,
+        Sprite::Bow,
+        Sprite::BowChargeFrame0,
+        Sprite::BowChargeFrame1,
+        Sprite::TekturRegular,
+        Sprite::TextButton,
+        Sprite::Triangles,
+    ];
+
+    /// Sorted by name, indexed by the sprite id
+    pub const NAMES: [&'static str; 10] = [
+        "arrow",
+        "block_sq2",
+        "block_sq3",
+        "block_sq4",
+        "bow",
+        "bow_charge_frame0",
+        "bow_charge_frame1",
+        "tektur_regular",
+        "text_button",
+        "triangles",
+    ];
+
+    pub fn view(self) -> TextureAtlasView {
+        match self {
+            Sprite::Arrow => TextureAtlas::view_arrow(),
+            Sprite::BlockSq2 => TextureAtlas::view_block_sq2(),
+            Sprite::BlockSq3 => TextureAtlas::view_block_sq3(),
+            Sprite::BlockSq4 => TextureAtlas::view_block_sq4(),
+            Sprite::Bow => TextureAtlas::view_bow(),
+            Sprite::BowChargeFrame0 => TextureAtlas::view_bow_charge_frame0(),
+            Sprite::BowChargeFrame1 => TextureAtlas::view_bow_charge_frame1(),
+            Sprite::TekturRegular => TextureAtlas::view_tektur_regular(),
+            Sprite::TextButton => TextureAtlas::view_text_button(),
+            Sprite::Triangles => TextureAtlas::view_triangles(),
+        }
+    }
+}
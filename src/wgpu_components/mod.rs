@@ -1,9 +1,11 @@
 mod shader;
+mod sparse_buffer;
 mod texture;
 mod uniform;
 mod vec_buffer;
 
 pub use shader::*;
+pub use sparse_buffer::*;
 pub use texture::*;
 pub use uniform::*;
 pub use vec_buffer::*;
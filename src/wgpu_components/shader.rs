@@ -11,11 +11,35 @@ pub struct Shader {
 pub struct ShaderDescriptor<'a> {
     pub src: Cow<'a, str>,
     pub textures: &'a [&'a wgpu::TextureView],
+    /// The view dimension of each texture, in the same order
+    pub texture_dimensions: &'a [wgpu::TextureViewDimension],
     pub uniforms: &'a [&'a wgpu::BindGroupLayout],
     pub vertex_layout: wgpu::VertexBufferLayout<'a>,
     pub output_format: wgpu::TextureFormat,
     pub blend: wgpu::BlendState,
     pub depth_stencil: Option<wgpu::DepthStencilState>,
+    pub topology: wgpu::PrimitiveTopology,
+}
+
+/// Draws triangle strips without textures, uniforms nor vertex attributes
+impl Default for ShaderDescriptor<'_> {
+    fn default() -> Self {
+        Self {
+            src: Cow::Borrowed(""),
+            textures: &[],
+            texture_dimensions: &[],
+            uniforms: &[],
+            vertex_layout: wgpu::VertexBufferLayout {
+                array_stride: 0,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &[],
+            },
+            output_format: wgpu::TextureFormat::Bgra8Unorm,
+            blend: wgpu::BlendState::REPLACE,
+            depth_stencil: None,
+            topology: wgpu::PrimitiveTopology::TriangleStrip,
+        }
+    }
 }
 
 impl Shader {
@@ -27,6 +51,12 @@ impl Shader {
                 source: wgpu::ShaderSource::Wgsl(desc.src),
             });
 
+        assert_eq!(
+            desc.textures.len(),
+            desc.texture_dimensions.len(),
+            "Each texture needs its view dimension"
+        );
+
         let sampler = Texture::create_linear_sampler(&ctx.device);
 
         let mut entries = Vec::with_capacity(desc.textures.len() + 1);
@@ -38,13 +68,13 @@ impl Shader {
             count: None,
         });
 
-        for binding in 1..=desc.textures.len() as u32 {
+        for (binding, view_dimension) in (1..).zip(desc.texture_dimensions) {
             entries.push(wgpu::BindGroupLayoutEntry {
                 binding,
                 visibility: wgpu::ShaderStages::FRAGMENT,
                 ty: wgpu::BindingType::Texture {
                     multisampled: false,
-                    view_dimension: wgpu::TextureViewDimension::D2,
+                    view_dimension: *view_dimension,
                     sample_type: wgpu::TextureSampleType::Float { filterable: true },
                 },
                 count: None,
@@ -90,7 +120,7 @@ impl Shader {
                     })],
                 }),
                 primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleStrip,
+                    topology: desc.topology,
                     strip_index_format: None,
                     front_face: wgpu::FrontFace::Ccw,
                     cull_mode: None,
@@ -1,14 +1,19 @@
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
+use std::ops::Range;
+use wgpu::{util::DeviceExt, BufferAddress};
 
 use crate::WgpuContext;
 
 pub trait Disable {
+    /// Makes the item have no effect (for example, not visible)
     fn disable(&mut self);
 }
 
 pub struct SparseVec<T: Disable> {
     data: Vec<T>,
+    /// Increased each time the slot is removed
+    generations: Vec<u32>,
     empty_slots: BinaryHeap<Reverse<usize>>,
 }
 
@@ -16,6 +21,7 @@ impl<T: Disable> SparseVec<T> {
     pub fn new() -> Self {
         Self {
             data: Vec::new(),
+            generations: Vec::new(),
             empty_slots: BinaryHeap::new(),
         }
     }
@@ -27,53 +33,228 @@ impl<T: Disable> SparseVec<T> {
             slot.0
         } else {
             self.data.push(item);
+            self.generations.push(0);
             self.data.len() - 1
         }
     }
 
+    /// The index can be reused by the next push.
+    /// Removing an index twice is a logic error.
     pub fn remove(&mut self, index: usize) {
+        debug_assert!(
+            !self.empty_slots.iter().any(|slot| slot.0 == index),
+            "Slot {index} removed twice"
+        );
         self.data[index].disable();
+        self.generations[index] = self.generations[index].wrapping_add(1);
         self.empty_slots.push(Reverse(index));
     }
 }
 
+/// Indices changed since the last `take`, each one recorded once
+#[derive(Default)]
+struct ChangeSet {
+    changes: Vec<usize>,
+    /// Whether each slot is in `changes`
+    changed: Vec<bool>,
+}
+
+impl ChangeSet {
+    /// All the `len` slots count as changed
+    fn all(len: usize) -> Self {
+        Self {
+            changes: (0..len).collect(),
+            changed: vec![true; len],
+        }
+    }
+
+    fn insert(&mut self, index: usize) {
+        if self.changed.len() <= index {
+            self.changed.resize(index + 1, false);
+        }
+        if !self.changed[index] {
+            self.changed[index] = true;
+            self.changes.push(index);
+        }
+    }
+
+    /// Sorted indices
+    fn take(&mut self) -> Vec<usize> {
+        let mut changes = std::mem::take(&mut self.changes);
+        for &index in &changes {
+            self.changed[index] = false;
+        }
+        changes.sort_unstable();
+        changes
+    }
+}
+
+/// A gpu buffer where the removed items leave a disabled hole
+/// that is filled by the following pushes
 pub struct SparseBuffer<T: Disable + bytemuck::NoUninit> {
     sparse: SparseVec<T>,
     buffer: wgpu::Buffer,
     update_range: Option<Range<usize>>,
+    /// `None` when the changes are not tracked
+    changes: Option<ChangeSet>,
 }
 
-impl<T: Disable> SparseBuffer<T> {
+impl<T: Disable + bytemuck::NoUninit> SparseBuffer<T> {
     pub fn new(ctx: &WgpuContext, usage: wgpu::BufferUsages) -> Self {
         Self {
             sparse: SparseVec::new(),
             buffer: ctx
                 .device
                 .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Vec buffer"),
+                    label: Some("Sparse buffer"),
                     contents: &[],
                     usage: usage | wgpu::BufferUsages::COPY_DST,
                 }),
             update_range: None,
+            changes: None,
         }
     }
 
-    pub fn get_mut(&mut self, index: usize) -> &mut T {
+    /// Starts recording the changed indices, all the current slots count as changed.
+    /// Each slot is recorded once until the next `take_changes`
+    pub fn track_changes(&mut self) {
+        if self.changes.is_none() {
+            self.changes = Some(ChangeSet::all(self.len()));
+        }
+    }
+
+    /// Changed indices since the last call, sorted
+    pub fn take_changes(&mut self) -> Vec<usize> {
+        self.changes
+            .as_mut()
+            .map(ChangeSet::take)
+            .unwrap_or_default()
+    }
+
+    /// Changes each time the slot is removed, to tell apart the items that reuse it
+    pub fn generation(&self, index: usize) -> u32 {
+        self.sparse.generations[index]
+    }
+
+    fn mark_updated(&mut self, index: usize) {
+        if let Some(changes) = &mut self.changes {
+            changes.insert(index);
+        }
+
         if let Some(range) = &mut self.update_range {
             range.start = range.start.min(index);
             range.end = range.end.max(index + 1);
         } else {
             self.update_range = Some(index..index + 1);
         }
+    }
 
+    pub fn get_ref(&self, index: usize) -> &T {
+        &self.sparse.data[index]
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> &mut T {
+        self.mark_updated(index);
         &mut self.sparse.data[index]
     }
 
+    /// Return the index of the item
     pub fn push(&mut self, item: T) -> usize {
-        self.sparse.push(item)
+        let index = self.sparse.push(item);
+        self.mark_updated(index);
+        index
     }
 
     pub fn remove(&mut self, index: usize) {
-        self.remove(index);
+        self.sparse.remove(index);
+        self.mark_updated(index);
+    }
+
+    /// Number of slots, including the removed ones
+    pub fn len(&self) -> usize {
+        self.sparse.data.len()
+    }
+
+    pub fn view(&mut self, ctx: &WgpuContext) -> Option<wgpu::BufferSlice<'_>> {
+        let data = &self.sparse.data;
+        if data.is_empty() {
+            return None;
+        }
+
+        let item_bytes: usize = std::mem::size_of::<T>();
+        let data_size = (data.len() * item_bytes) as BufferAddress;
+
+        if let Some(update_range) = self.update_range.clone() {
+            if self.buffer.size() < data_size {
+                // Realocate Buffer
+                self.buffer = ctx
+                    .device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some("Sparse buffer"),
+                        contents: bytemuck::cast_slice(&data[..]),
+                        usage: self.buffer.usage(),
+                    });
+            } else {
+                // Update Buffer
+                ctx.queue.write_buffer(
+                    &self.buffer,
+                    (update_range.start * item_bytes) as BufferAddress,
+                    bytemuck::cast_slice(&data[update_range]),
+                );
+            }
+            self.update_range = None;
+        }
+
+        Some(self.buffer.slice(0..data_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct Item(u32);
+
+    impl Disable for Item {
+        fn disable(&mut self) {
+            self.0 = 0;
+        }
+    }
+
+    #[test]
+    fn removed_slots_are_reused_lowest_first() {
+        let mut items = SparseVec::new();
+        for i in 1..=4 {
+            items.push(Item(i));
+        }
+        items.remove(2);
+        items.remove(0);
+        assert_eq!(items.data[2], Item(0));
+        assert_eq!(items.generations, [1, 0, 1, 0]);
+
+        assert_eq!(items.push(Item(5)), 0);
+        assert_eq!(items.push(Item(6)), 2);
+        assert_eq!(items.push(Item(7)), 4);
+        assert_eq!(items.data, [5, 2, 6, 4, 7].map(Item));
+        // Pushing keeps the generation of the removal
+        assert_eq!(items.generations, [1, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn changes_are_recorded_once() {
+        let mut changes = ChangeSet::all(3);
+        changes.insert(1);
+        assert_eq!(changes.take(), [0, 1, 2]);
+        assert_eq!(changes.take(), []);
+
+        changes.insert(5);
+        changes.insert(1);
+        changes.insert(5);
+        changes.insert(1);
+        assert_eq!(changes.take(), [1, 5]);
+
+        changes.insert(5);
+        assert_eq!(changes.take(), [5]);
     }
 }
@@ -6,28 +6,44 @@ pub struct Texture {
 }
 
 impl Texture {
-    pub fn from_bytes(ctx: &WgpuContext, bytes: &[u8], label: &str) -> ErrResult<Self> {
-        let img = image::load_from_memory(bytes)?;
-        Self::from_image(ctx, &img, Some(label))
+    /// Creates a texture array with a layer for each image
+    pub fn from_bytes_array(
+        ctx: &WgpuContext,
+        layers: &[&[u8]],
+        mip_levels: u32,
+        label: &str,
+    ) -> ErrResult<Self> {
+        let images = layers
+            .iter()
+            .map(|bytes| image::load_from_memory(bytes))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_images(ctx, &images, mip_levels, Some(label))
     }
 
-    pub fn from_image(
+    /// All the images must have the same dimensions.
+    /// The smaller mip levels are generated from the images.
+    pub fn from_images(
         ctx: &WgpuContext,
-        img: &image::DynamicImage,
+        images: &[image::DynamicImage],
+        mip_levels: u32,
         label: Option<&str>,
     ) -> ErrResult<Self> {
-        let rgba = img.to_rgba8();
-        let dimensions = rgba.dimensions();
+        let first = images.first().ok_or("A texture needs at least one image")?;
+        let (width, height) = (first.width(), first.height());
+
+        // Stop before a level gets smaller than a pixel
+        let max_mip_levels = u32::BITS - width.min(height).leading_zeros();
+        let mip_level_count = mip_levels.clamp(1, max_mip_levels);
 
         let size = wgpu::Extent3d {
-            width: dimensions.0,
-            height: dimensions.1,
-            depth_or_array_layers: 1,
+            width,
+            height,
+            depth_or_array_layers: images.len() as u32,
         };
         let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
             label,
             size,
-            mip_level_count: 1,
+            mip_level_count,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Rgba8Unorm,
@@ -35,27 +51,80 @@ impl Texture {
             view_formats: &[],
         });
 
-        ctx.queue.write_texture(
-            wgpu::ImageCopyTexture {
-                aspect: wgpu::TextureAspect::All,
-                texture: &texture,
-                mip_level: 0,
-                origin: wgpu::Origin3d::ZERO,
-            },
-            &rgba,
-            wgpu::ImageDataLayout {
-                offset: 0,
-                bytes_per_row: Some(4 * dimensions.0),
-                rows_per_image: Some(dimensions.1),
-            },
-            size,
-        );
+        for (layer, img) in images.iter().enumerate() {
+            if img.width() != width || img.height() != height {
+                return Err("All the layers of a texture must have the same size".into());
+            }
 
-        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let mut mip = img.to_rgba8();
+
+            for mip_level in 0..mip_level_count {
+                if mip_level > 0 {
+                    mip = Self::next_mip(&mip);
+                }
+
+                ctx.queue.write_texture(
+                    wgpu::ImageCopyTexture {
+                        aspect: wgpu::TextureAspect::All,
+                        texture: &texture,
+                        mip_level,
+                        origin: wgpu::Origin3d {
+                            x: 0,
+                            y: 0,
+                            z: layer as u32,
+                        },
+                    },
+                    &mip,
+                    wgpu::ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: Some(4 * mip.width()),
+                        rows_per_image: Some(mip.height()),
+                    },
+                    wgpu::Extent3d {
+                        width: mip.width(),
+                        height: mip.height(),
+                        depth_or_array_layers: 1,
+                    },
+                );
+            }
+        }
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::D2Array),
+            ..Default::default()
+        });
 
         Ok(Self { texture, view })
     }
 
+    /// Halves the image with a box filter weighted by alpha,
+    /// so transparent pixels don't darken the borders
+    fn next_mip(image: &image::RgbaImage) -> image::RgbaImage {
+        let (width, height) = image.dimensions();
+
+        image::RgbaImage::from_fn((width / 2).max(1), (height / 2).max(1), |x, y| {
+            let mut color = [0f32; 3];
+            let mut alpha = 0f32;
+
+            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let pixel =
+                    image.get_pixel((x * 2 + dx).min(width - 1), (y * 2 + dy).min(height - 1));
+                let a = pixel[3] as f32;
+                for c in 0..3 {
+                    color[c] += pixel[c] as f32 * a;
+                }
+                alpha += a;
+            }
+
+            if alpha == 0. {
+                return image::Rgba([0, 0, 0, 0]);
+            }
+
+            let [r, g, b] = color.map(|c| (c / alpha).round() as u8);
+            image::Rgba([r, g, b, (alpha / 4.).round() as u8])
+        })
+    }
+
     pub fn create_linear_sampler(device: &wgpu::Device) -> wgpu::Sampler {
         device.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: wgpu::AddressMode::ClampToEdge,
@@ -63,7 +132,7 @@ impl Texture {
             address_mode_w: wgpu::AddressMode::ClampToEdge,
             mag_filter: wgpu::FilterMode::Linear,
             min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Linear,
             ..Default::default()
         })
     }
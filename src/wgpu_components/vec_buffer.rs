@@ -23,10 +23,6 @@ impl<T: bytemuck::NoUninit> VecBuffer<T> {
         }
     }
 
-    pub fn get_ref(&self, index: usize) -> &T {
-        &self.data[index]
-    }
-
     pub fn get_mut(&mut self, index: usize) -> &mut T {
         if let Some(range) = &mut self.update_range {
             range.start = range.start.min(index);
@@ -54,6 +50,30 @@ impl<T: bytemuck::NoUninit> VecBuffer<T> {
         index
     }
 
+    /// Appends all the items
+    pub fn extend(&mut self, items: impl IntoIterator<Item = T>) {
+        let start = self.data.len();
+        self.data.extend(items);
+
+        let end = self.data.len();
+        if start == end {
+            return;
+        }
+
+        if let Some(range) = &mut self.update_range {
+            range.start = range.start.min(start);
+            range.end = end;
+        } else {
+            self.update_range = Some(start..end);
+        }
+    }
+
+    /// Removes all the items, keeping the gpu buffer for the following pushes
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.update_range = None;
+    }
+
     pub fn len(&self) -> usize {
         self.data.len()
     }